@@ -1,15 +1,24 @@
 use crate::{
     game_state::{GameState, SettlementState},
     game_time::GameTimeAdvancedEvent,
-    types::{Ship, ShipSize},
+    resources::Resource,
+    settlement::Settlement,
+    types::{Ship, ShipClass},
 };
 use bevy::prelude::*;
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
 
 mod shipyard_ui;
 mod temple_ui;
 
-#[derive(Deserialize, Debug)]
+#[cfg(test)]
+mod tests;
+
+/// share of the build time added when all materials are missing
+const MISSING_MATERIALS_DELAY: f32 = 1.;
+
+#[derive(Deserialize, Debug, Clone)]
 pub struct Building {
     pub building_type: BuildingType,
     pub entity: Option<Entity>,
@@ -24,7 +33,7 @@ impl From<BuildingType> for Building {
     }
 }
 
-#[derive(Component, Debug, Default)]
+#[derive(Component, Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Temple {
     pub info: TempleInfo,
     pub offers_made: u32,
@@ -38,27 +47,232 @@ impl From<TempleInfo> for Temple {
     }
 }
 
-#[derive(Deserialize, Debug, Default, Clone)]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
 pub struct TempleInfo {
     pub deity: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ShipyardInfo {
+    /// names of the ship classes the shipyard builds
+    pub ships: Vec<String>,
+    /// orders the shipyard works on at the same time
+    #[serde(default = "default_slots")]
+    pub slots: u32,
+}
+
+fn default_slots() -> u32 {
+    1
+}
+
+impl Default for ShipyardInfo {
+    fn default() -> Self {
+        Self {
+            ships: vec![],
+            slots: default_slots(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ShipyardTask {
-    Construction(ShipSize),
+    Construction(ShipClass),
     Repair(Ship),
 }
 
-#[derive(Component, Debug, Default)]
+impl std::fmt::Display for ShipyardTask {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ShipyardTask::Construction(ship_class) => {
+                write!(f, "Construction of {}", ship_class.name)
+            }
+            ShipyardTask::Repair(ship) => write!(f, "Repair of {}", ship),
+        }
+    }
+}
+
+impl ShipyardTask {
+    /// resources the task needs, repairs need a share of the materials
+    /// of a new ship
+    pub fn materials(&self) -> HashMap<String, u32> {
+        match self {
+            ShipyardTask::Construction(ship_class) => ship_class.materials.clone(),
+            ShipyardTask::Repair(ship) => ship
+                .class
+                .materials
+                .iter()
+                .map(|(resource, amount)| {
+                    let needed = (*amount as f32 * (1. - ship.health())).ceil() as u32;
+                    (resource.clone(), needed)
+                })
+                .collect(),
+        }
+    }
+
+    /// seasons the task takes with all materials at hand
+    pub fn base_time(&self) -> u32 {
+        match self {
+            ShipyardTask::Construction(ship_class) => ship_class.build_time,
+            ShipyardTask::Repair(ship) => ship.repair_time(),
+        }
+    }
+
+    /// share of the materials the settlement has in stock
+    pub fn materials_available(&self, settlement: &Settlement) -> f32 {
+        let (available, needed) =
+            self.materials()
+                .iter()
+                .fold((0, 0), |(available, needed), (resource, amount)| {
+                    let stock = *settlement.resources.get(resource).unwrap_or(&0);
+                    (available + u32::min(stock, *amount), needed + amount)
+                });
+
+        if needed == 0 {
+            1.
+        } else {
+            available as f32 / needed as f32
+        }
+    }
+
+    /// what the settlement charges for the materials it has in stock
+    pub fn materials_price(&self, settlement: &Settlement, resources: &[Resource]) -> u32 {
+        self.materials()
+            .iter()
+            .filter_map(|(name, amount)| {
+                let stock = *settlement.resources.get(name).unwrap_or(&0);
+                let resource = resources.iter().find(|resource| &resource.name == name)?;
+
+                Some(
+                    settlement
+                        .prices(resource)
+                        .buy_total(u32::min(stock, *amount)),
+                )
+            })
+            .sum()
+    }
+
+    /// seasons the task takes with the materials of the settlement
+    pub fn time(&self, settlement: &Settlement) -> u32 {
+        let missing = 1. - self.materials_available(settlement);
+
+        (self.base_time() as f32 * (1. + MISSING_MATERIALS_DELAY * missing)).ceil() as u32
+    }
+
+    /// the ship handed over when the task is done
+    pub fn finish(self) -> Ship {
+        match self {
+            ShipyardTask::Construction(ship_class) => Ship::new(&ship_class),
+            ShipyardTask::Repair(mut ship) => {
+                ship.damage = 0;
+                ship
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipyardOrder {
+    pub task: ShipyardTask,
+    pub time_remaining: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShipyardOrderStatus {
+    Ready,
+    InProgress(u32),
+    Waiting,
+}
+
+#[derive(Component, Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Shipyard {
-    pub task: Option<ShipyardTask>,
-    pub task_time_remaining: u32,
+    pub info: ShipyardInfo,
+    pub orders: Vec<ShipyardOrder>,
+}
+
+impl From<ShipyardInfo> for Shipyard {
+    fn from(info: ShipyardInfo) -> Self {
+        Self { info, ..default() }
+    }
+}
+
+impl Shipyard {
+    /// Buys the materials for the task from the settlement and queues it.
+    /// Missing materials make the task take longer. The one ordering pays
+    /// the `materials_price` of the task on top of the price of the work.
+    pub fn order(
+        &mut self,
+        task: ShipyardTask,
+        settlement: &mut Settlement,
+        resources: &[Resource],
+    ) {
+        let time_remaining = task.time(settlement);
+        settlement.silver += task.materials_price(settlement, resources);
+
+        for (name, amount) in task.materials() {
+            let stock = *settlement.resources.get(&name).unwrap_or(&0);
+            let taken = u32::min(stock, amount);
+            if taken > 0 {
+                settlement.resources.insert(name, stock - taken);
+            }
+        }
+
+        self.orders.push(ShipyardOrder {
+            task,
+            time_remaining,
+        });
+    }
+
+    /// unfinished orders beyond the slots of the shipyard wait
+    pub fn status(&self, index: usize) -> ShipyardOrderStatus {
+        let order = &self.orders[index];
+        if order.time_remaining == 0 {
+            return ShipyardOrderStatus::Ready;
+        }
+
+        let ahead = self.orders[..index]
+            .iter()
+            .filter(|order| order.time_remaining > 0)
+            .count() as u32;
+        if ahead < self.info.slots {
+            ShipyardOrderStatus::InProgress(order.time_remaining)
+        } else {
+            ShipyardOrderStatus::Waiting
+        }
+    }
+
+    pub fn slots_busy(&self) -> u32 {
+        u32::min(
+            self.orders
+                .iter()
+                .filter(|order| order.time_remaining > 0)
+                .count() as u32,
+            self.info.slots,
+        )
+    }
+
+    /// advances the orders in the slots by one season
+    pub fn work(&mut self) {
+        let slots = self.info.slots as usize;
+        for order in self
+            .orders
+            .iter_mut()
+            .filter(|order| order.time_remaining > 0)
+            .take(slots)
+        {
+            order.time_remaining -= 1;
+        }
+    }
+
+    pub fn pick_up(&mut self, index: usize) -> Ship {
+        self.orders.remove(index).task.finish()
+    }
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(tag = "type")]
 pub enum BuildingType {
-    Shipyard,
+    Shipyard(ShipyardInfo),
     Temple(TempleInfo),
 }
 
@@ -74,15 +288,20 @@ where
         .collect())
 }
 
+pub fn building_serialize<S>(buildings: &[Building], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(buildings.iter().map(|building| &building.building_type))
+}
+
 fn shipyard_construction(
     mut shipyards: Query<&mut Shipyard>,
     mut events: EventReader<GameTimeAdvancedEvent>,
 ) {
     for _ in events.iter() {
         for mut shipyard in shipyards.iter_mut() {
-            if shipyard.task_time_remaining > 0 {
-                shipyard.task_time_remaining -= 1;
-            }
+            shipyard.work();
         }
     }
 }
@@ -1,8 +1,10 @@
 use crate::{
-    building::Shipyard,
+    building::{Shipyard, ShipyardOrderStatus},
     game_state::{GameState, SettlementState},
-    types::{Player, Ship, ShipSize},
-    ui::{create_window, enabled_color, large_button, SelectedBuilding},
+    resources::Resource,
+    settlement::Settlement,
+    types::{Hold, Player, Ship, ShipClass},
+    ui::{create_window, enabled_color, large_button, SelectedBuilding, SelectedSettlement},
     COIN_NAME,
 };
 use bevy::prelude::*;
@@ -10,59 +12,130 @@ use bevy_egui::{
     egui::{self, Align, RichText},
     EguiContext,
 };
+use std::collections::HashMap;
 
 use super::ShipyardTask;
 
+#[derive(Default)]
 pub struct ShipTextures {
     #[allow(dead_code)]
     references: Vec<Handle<Image>>,
-    small: egui::TextureId,
-    medium: egui::TextureId,
-    large: egui::TextureId,
+    images: HashMap<String, egui::TextureId>,
 }
 
 impl ShipTextures {
-    fn transport_type_image(&self, ship_size: ShipSize) -> egui::TextureId {
-        match ship_size {
-            ShipSize::Small => self.small,
-            ShipSize::Medium => self.medium,
-            ShipSize::Large => self.large,
+    fn load(&mut self, image: &str, asset_server: &AssetServer, egui_context: &mut EguiContext) {
+        if !self.images.contains_key(image) {
+            let handle: Handle<Image> = asset_server.load(image);
+            self.images.insert(
+                image.to_owned(),
+                egui_context.add_image(handle.clone_weak()),
+            );
+            self.references.push(handle);
+        }
+    }
+
+    fn ship_class_image(&self, ship_class: &ShipClass) -> egui::TextureId {
+        *self
+            .images
+            .get(&ship_class.image)
+            .expect("ship images to be loaded")
+    }
+}
+
+fn status_text(status: ShipyardOrderStatus) -> String {
+    match status {
+        ShipyardOrderStatus::Ready => "ready for pickup".to_owned(),
+        ShipyardOrderStatus::InProgress(1) => "in progress for one more season".to_owned(),
+        ShipyardOrderStatus::InProgress(seasons) => {
+            format!("in progress for {} more seasons", seasons)
+        }
+        ShipyardOrderStatus::Waiting => "waiting for a free slot".to_owned(),
+    }
+}
+
+/// orders of the player at all shipyards with the settlement they are placed in
+fn all_orders(
+    settlements: &Query<&mut Settlement>,
+    shipyards: &Query<&mut Shipyard>,
+) -> Vec<(String, String, ShipyardOrderStatus)> {
+    let mut orders = vec![];
+
+    for settlement in settlements.iter() {
+        for building in settlement.buildings.iter() {
+            if let Some(shipyard) = building
+                .entity
+                .and_then(|entity| shipyards.get(entity).ok())
+            {
+                for (index, order) in shipyard.orders.iter().enumerate() {
+                    orders.push((
+                        settlement.name.clone(),
+                        order.task.to_string(),
+                        shipyard.status(index),
+                    ));
+                }
+            }
         }
     }
+
+    orders.sort_by(|a, b| a.0.cmp(&b.0));
+    orders
+}
+
+fn materials_text(materials: &HashMap<String, u32>) -> String {
+    let mut materials: Vec<(&String, &u32)> = materials.iter().collect();
+    materials.sort();
+
+    materials
+        .into_iter()
+        .map(|(resource, amount)| format!("{} {}", resource, amount))
+        .collect::<Vec<String>>()
+        .join(", ")
 }
 
 pub fn shipyard_ui(
     mut shipyards: Query<&mut Shipyard>,
-    mut ship_textures: Local<Option<ShipTextures>>,
+    mut settlements: Query<&mut Settlement>,
+    mut ship_textures: Local<ShipTextures>,
+    mut trade_in: Local<Option<usize>>,
     ui_resources: (Res<AssetServer>, Res<Windows>, ResMut<EguiContext>),
-    resources: (
+    state: (
         ResMut<Player>,
         ResMut<State<GameState>>,
-        Option<Res<SelectedBuilding>>,
+        (
+            Option<Res<SelectedSettlement>>,
+            Option<Res<SelectedBuilding>>,
+        ),
+        Res<Vec<ShipClass>>,
+        Res<Vec<Resource>>,
     ),
 ) {
     let (asset_server, windows, mut egui_context) = ui_resources;
-    let (mut player, mut game_state, selected_building) = resources;
-
-    if ship_textures.is_none() {
-        let small = asset_server.load("images/ship_small.png");
-        let medium = asset_server.load("images/ship_medium.png");
-        let large = asset_server.load("images/ship_large.png");
-
-        let textures = ShipTextures {
-            small: egui_context.add_image(small.clone_weak()),
-            medium: egui_context.add_image(medium.clone_weak()),
-            large: egui_context.add_image(large.clone_weak()),
-            references: vec![small, medium, large],
-        };
-        *ship_textures = Some(textures);
-    }
+    let (mut player, mut game_state, selected, ship_classes, resources) = state;
+    let (selected_settlement, selected_building) = selected;
 
-    if let Some(entity) = selected_building.as_ref() {
-        if let Ok(mut shipyard) = shipyards.get_mut(entity.0) {
+    let all_orders = all_orders(&settlements, &shipyards);
+
+    if let (Some(settlement), Some(building)) =
+        (selected_settlement.as_ref(), selected_building.as_ref())
+    {
+        if let (Ok(mut settlement), Ok(mut shipyard)) = (
+            settlements.get_mut(settlement.0),
+            shipyards.get_mut(building.0),
+        ) {
             let mut open = true;
 
-            let ship_textures = ship_textures.as_ref().unwrap();
+            let offered_classes: Vec<&ShipClass> = shipyard
+                .info
+                .ships
+                .iter()
+                .filter_map(|name| ship_classes.iter().find(|class| class.name == *name))
+                .collect();
+
+            for ship_class in offered_classes.iter() {
+                ship_textures.load(&ship_class.image, &asset_server, &mut egui_context);
+            }
+            let ship_textures = &*ship_textures;
 
             create_window(
                 egui_context.ctx_mut(),
@@ -79,61 +152,90 @@ pub fn shipyard_ui(
                         }
                     });
 
-                    if let Some(task) = shipyard.task.clone() {
-                        let ship_size = match task {
-                            ShipyardTask::Construction(size) => size,
-                            ShipyardTask::Repair(ship) => ship.size,
-                        };
-
-                        ui.with_layout(
-                            egui::Layout::from_main_dir_and_cross_align(
-                                egui::Direction::TopDown,
-                                egui::Align::Center,
-                            ),
-                            |ui| {
-                                ui.add(egui::widgets::Image::new(
-                                    ship_textures.transport_type_image(ship_size),
-                                    [250.0, 200.0],
+                    ui.label(format!(
+                        "{} of {} slots busy",
+                        shipyard.slots_busy(),
+                        shipyard.info.slots
+                    ));
+
+                    if !shipyard.orders.is_empty() {
+                        ui.add_space(10.);
+                        ui.heading("Your orders");
+                        ui.add_space(5.);
+
+                        let mut picked_up = None;
+                        for index in 0..shipyard.orders.len() {
+                            let status = shipyard.status(index);
+                            ui.horizontal(|ui| {
+                                ui.label(format!(
+                                    " - {}, {}",
+                                    shipyard.orders[index].task,
+                                    status_text(status)
                                 ));
-                                ui.heading(match task {
-                                    ShipyardTask::Construction(size) => {
-                                        format!("Construction of {}", Ship::new(size))
-                                    }
-                                    ShipyardTask::Repair(ship) => format!("Repair of {}", ship),
-                                });
-                                ui.add_space(5.);
-
-                                if shipyard.task_time_remaining == 0 {
-                                    ui.label("Ready for pickup");
-                                    ui.add_space(10.);
-
-                                    if large_button(ui, 200., "Add to convoy").clicked() {
-                                        shipyard.task = None;
-                                        player.convoy.push(match task {
-                                            ShipyardTask::Construction(size) => Ship::new(size),
-                                            ShipyardTask::Repair(ship) => ship,
-                                        });
-                                    }
-                                } else if shipyard.task_time_remaining == 1 {
-                                    ui.label("In progress for one more seasons");
-                                } else {
-                                    ui.label(format!(
-                                        "In progress for {} more seasons",
-                                        shipyard.task_time_remaining
-                                    ));
+                                if status == ShipyardOrderStatus::Ready
+                                    && ui.button("Add to convoy").clicked()
+                                {
+                                    picked_up = Some(index);
                                 }
-                            },
-                        );
+                            });
+                        }
+
+                        if let Some(index) = picked_up {
+                            let ship = shipyard.pick_up(index);
+                            player.convoy.push(ship);
+                        }
+                    }
+
+                    // forget a trade in that is no longer possible
+                    if let Some(index) = *trade_in {
+                        if index >= player.convoy.len()
+                            || !player.can_remove_ship(index, &resources)
+                        {
+                            *trade_in = None;
+                        }
+                    }
+                    let credit = trade_in
+                        .map(|index| player.convoy[index].sale_price())
+                        .unwrap_or(0);
+
+                    if offered_classes.is_empty() {
+                        ui.add_space(25.);
+                        ui.label("This shipyard does not build new ships.");
                     } else {
+                        if !player.convoy.is_empty() {
+                            ui.add_space(10.);
+                            egui::ComboBox::from_label("Trade in")
+                                .selected_text(match *trade_in {
+                                    Some(index) => player.hold_name(Hold::Ship(index)),
+                                    None => "nothing".to_owned(),
+                                })
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(&mut *trade_in, None, "nothing");
+                                    for index in 0..player.convoy.len() {
+                                        if player.can_remove_ship(index, &resources) {
+                                            ui.selectable_value(
+                                                &mut *trade_in,
+                                                Some(index),
+                                                format!(
+                                                    "{} ({} {})",
+                                                    player.hold_name(Hold::Ship(index)),
+                                                    player.convoy[index].sale_price(),
+                                                    COIN_NAME
+                                                ),
+                                            );
+                                        }
+                                    }
+                                });
+                        }
+
                         ui.add_space(25.);
-                        ui.columns(3, |columns| {
-                            for (index, ship_size) in
-                                vec![ShipSize::Small, ShipSize::Medium, ShipSize::Large]
-                                    .into_iter()
-                                    .enumerate()
-                            {
-                                let ship = Ship::new(ship_size);
-                                let enabled = player.silver >= ship.price();
+                        ui.columns(offered_classes.len(), |columns| {
+                            for (index, ship_class) in offered_classes.iter().enumerate() {
+                                let ship = Ship::new(ship_class);
+                                let task = ShipyardTask::Construction((*ship_class).clone());
+                                let materials_price = task.materials_price(&settlement, &resources);
+                                let price = (ship.price() + materials_price).saturating_sub(credit);
+                                let enabled = player.silver >= price;
 
                                 columns[index].with_layout(
                                     egui::Layout::from_main_dir_and_cross_align(
@@ -142,11 +244,42 @@ pub fn shipyard_ui(
                                     ),
                                     |ui| {
                                         ui.add(egui::widgets::Image::new(
-                                            ship_textures.transport_type_image(ship_size),
+                                            ship_textures.ship_class_image(ship_class),
                                             [100.0, 80.0],
                                         ));
                                         ui.heading(format!("{}", ship));
-                                        ui.label(format!("{} {}", ship.price(), COIN_NAME));
+                                        let price_label = if credit > 0 {
+                                            ui.label(format!(
+                                                "{} {} after trade in",
+                                                price, COIN_NAME
+                                            ))
+                                        } else {
+                                            ui.label(format!("{} {}", price, COIN_NAME))
+                                        };
+                                        if materials_price > 0 {
+                                            price_label.on_hover_text(format!(
+                                                "Including {} {} for materials from the town",
+                                                materials_price, COIN_NAME
+                                            ));
+                                        }
+                                        ui.label(format!(
+                                            "Cargo {}, Hull {}",
+                                            ship_class.capacity, ship_class.hull
+                                        ));
+                                        ui.label(format!(
+                                            "Speed {}, Crew {}",
+                                            ship_class.speed, ship_class.crew
+                                        ));
+                                        ui.label(format!(
+                                            "Built in {} seasons",
+                                            task.time(&settlement)
+                                        ))
+                                        .on_hover_text(
+                                            format!(
+                                                "Needs {}, missing materials slow down the work",
+                                                materials_text(&ship_class.materials)
+                                            ),
+                                        );
                                     },
                                 );
 
@@ -155,13 +288,15 @@ pub fn shipyard_ui(
                                 let button = columns[index].add_sized(
                                     [100., 30.],
                                     egui::Button::new(
-                                        RichText::new("Construct").color(enabled_color(enabled)),
+                                        RichText::new("Order").color(enabled_color(enabled)),
                                     ),
                                 );
                                 if button.clicked() && enabled {
-                                    player.silver -= ship.price();
-                                    shipyard.task = Some(ShipyardTask::Construction(ship_size));
-                                    shipyard.task_time_remaining = ship.construction_time();
+                                    if let Some(index) = trade_in.take() {
+                                        player.remove_ship(index, &resources);
+                                    }
+                                    player.silver -= price;
+                                    shipyard.order(task, &mut settlement, &resources);
                                 }
                             }
                         });
@@ -180,35 +315,106 @@ pub fn shipyard_ui(
                         ui.heading("Damaged ships in your convoy");
                         ui.add_space(5.);
 
+                        let mut repaired = None;
                         for (index, ship) in damaged_ships {
                             ui.horizontal(|ui| {
                                 ui.label(format!(" - {}", ship));
-                                let price = ship.repair_price();
+                                let task = ShipyardTask::Repair(ship.clone());
+                                let price = ship.repair_price()
+                                    + task.materials_price(&settlement, &resources);
+                                let seasons = task.time(&settlement);
                                 let can_afford = player.silver >= price;
-                                let can_store_resources =
-                                    player.resource_space_left() >= ship.resource_space();
-                                let enabled = can_afford && can_store_resources;
+                                let can_remove = player.can_remove_ship(index, &resources);
+                                let enabled = can_afford && can_remove;
 
                                 let button = ui.button(
-                                    RichText::new(format!("Repair ({} {})", price, COIN_NAME))
-                                        .color(enabled_color(enabled)),
+                                    RichText::new(format!(
+                                        "Repair ({} {}, {} seasons)",
+                                        price, COIN_NAME, seasons
+                                    ))
+                                    .color(enabled_color(enabled)),
                                 );
 
                                 if button.clicked() && enabled {
-                                    player.silver -= price;
-                                    let mut ship = player.convoy.remove(index);
-                                    shipyard.task_time_remaining = ship.repair_time();
-                                    ship.damage = 0;
-                                    shipyard.task = Some(ShipyardTask::Repair(ship));
+                                    repaired = Some((index, price));
+                                }
+
+                                if !can_remove {
+                                    ui.label("its cargo does not fit in the rest of the convoy");
                                 }
+                            });
+                        }
+
+                        if let Some((index, price)) = repaired {
+                            if let Some(ship) = player.remove_ship(index, &resources) {
+                                player.silver -= price;
+                                *trade_in = None;
+                                shipyard.order(
+                                    ShipyardTask::Repair(ship),
+                                    &mut settlement,
+                                    &resources,
+                                );
+                            }
+                        }
+                    }
 
-                                if !can_store_resources {
-                                    ui.label(
-                                        "ship is used to store resources and cannot be repaired",
-                                    );
+                    if !player.convoy.is_empty() {
+                        ui.add_space(10.);
+                        ui.heading("Sell ships");
+                        ui.add_space(5.);
+
+                        let mut sold = None;
+                        for index in 0..player.convoy.len() {
+                            let ship = &player.convoy[index];
+                            let can_remove = player.can_remove_ship(index, &resources);
+
+                            ui.horizontal(|ui| {
+                                ui.label(format!(
+                                    " - {} ({}%)",
+                                    player.hold_name(Hold::Ship(index)),
+                                    (ship.health() * 100.).floor()
+                                ));
+                                if ui
+                                    .add_enabled(
+                                        can_remove,
+                                        egui::Button::new(format!(
+                                            "Sell ({} {})",
+                                            ship.sale_price(),
+                                            COIN_NAME
+                                        )),
+                                    )
+                                    .on_disabled_hover_text(
+                                        "Its cargo does not fit in the rest of the convoy, \
+                                        sell or move goods first",
+                                    )
+                                    .clicked()
+                                {
+                                    sold = Some(index);
                                 }
                             });
                         }
+
+                        if let Some(index) = sold {
+                            if let Some(ship) = player.remove_ship(index, &resources) {
+                                player.silver += ship.sale_price();
+                                *trade_in = None;
+                                log::info!("sold {} for {}", ship, ship.sale_price());
+                            }
+                        }
+                    }
+
+                    if !all_orders.is_empty() {
+                        ui.add_space(10.);
+                        egui::CollapsingHeader::new("All your orders").show(ui, |ui| {
+                            for (settlement, task, status) in all_orders.iter() {
+                                ui.label(format!(
+                                    " - {}: {}, {}",
+                                    settlement,
+                                    task,
+                                    status_text(*status)
+                                ));
+                            }
+                        });
                     }
                 },
             );
@@ -1,9 +1,12 @@
 use crate::{
     building::Temple,
     game_state::{GameState, SettlementState},
-    types::Player,
+    game_time::GameTime,
+    player::cargo_value,
+    price_calculator::AveragePrices,
+    types::{InsuranceTerm, Player},
     ui::{create_window, enabled_color, large_button, SelectedBuilding},
-    COIN_NAME,
+    Settings, COIN_NAME,
 };
 use bevy::prelude::*;
 use bevy_egui::{
@@ -26,9 +29,11 @@ pub fn temple_ui(
         ResMut<State<GameState>>,
         Option<Res<SelectedBuilding>>,
     ),
+    insurance: (Res<AveragePrices>, Res<GameTime>, Res<Settings>),
 ) {
     let (asset_server, windows, mut egui_context) = ui_resources;
     let (mut player, mut game_state, selected_building) = resources;
+    let (average_prices, game_time, settings) = insurance;
 
     if temple_textures.is_none() {
         let image = asset_server.load("images/temple.png");
@@ -192,6 +197,71 @@ pub fn temple_ui(
                                     }
                                 });
                             }
+
+                            ui.add_space(10.);
+                            ui.heading("Insurance of your cargo");
+                            ui.add_space(5.);
+
+                            {
+                                if let Some(insurance) = &player.insurance {
+                                    ui.label(format!(
+                                        "Cargo worth {} {} is insured for {}",
+                                        insurance.value,
+                                        COIN_NAME,
+                                        match insurance.term {
+                                            InsuranceTerm::Voyage => "the next voyage",
+                                            InsuranceTerm::Season { .. } =>
+                                                "voyages starting this season",
+                                        }
+                                    ));
+                                    ui.add_space(5.);
+                                }
+
+                                let value = cargo_value(&player.cargo(), &average_prices);
+                                let terms = [
+                                    ("next voyage", InsuranceTerm::Voyage),
+                                    (
+                                        "this season",
+                                        InsuranceTerm::Season {
+                                            year: game_time.year,
+                                            season: game_time.season,
+                                        },
+                                    ),
+                                ];
+
+                                ui.columns(2, |columns| {
+                                    for (index, (name, term)) in terms.into_iter().enumerate() {
+                                        let premium = player.insurance_premium(
+                                            term,
+                                            value,
+                                            &game_time,
+                                            &settings.insurance,
+                                        );
+                                        let enabled = value > 0 && player.silver >= premium;
+                                        let width = columns[index].available_width();
+                                        let button = columns[index]
+                                            .add_sized(
+                                                [width, 30.],
+                                                egui::Button::new(
+                                                    RichText::new(format!(
+                                                        "Insure {} ({} {})",
+                                                        name, premium, COIN_NAME
+                                                    ))
+                                                    .color(enabled_color(enabled)),
+                                                ),
+                                            )
+                                            .on_hover_text(format!(
+                                                "Pays out part of the value of cargo worth up to \
+                                                {} {} when a ship is lost at sea",
+                                                value, COIN_NAME
+                                            ));
+
+                                        if button.clicked() && enabled {
+                                            player.insure(term, value, premium);
+                                        }
+                                    }
+                                });
+                            }
                         },
                     );
                 },
@@ -0,0 +1,90 @@
+use super::*;
+
+fn ship_class() -> ShipClass {
+    let mut materials = HashMap::new();
+    materials.insert("Wood".to_owned(), 10);
+    materials.insert("Pitch".to_owned(), 10);
+
+    ShipClass {
+        build_time: 4,
+        materials,
+        ..ShipClass::test_class("Small Ship", 20)
+    }
+}
+
+fn setup() -> (Shipyard, Settlement, Vec<Resource>) {
+    let mut settlement = Settlement::test_city("Histria", &["Lumberjack"], &[("Wood", 30)]);
+    settlement.silver = 1000;
+
+    let resources: Vec<Resource> = serde_yaml::from_str(
+        "
+- name: Wood
+  base_price: 10
+  demand:
+    Population: 1
+  max:
+    Population: 50
+- name: Pitch
+  base_price: 20
+  demand:
+    Population: 1
+  max:
+    Population: 50
+",
+    )
+    .unwrap();
+
+    let shipyard = Shipyard::from(ShipyardInfo {
+        ships: vec!["Small Ship".to_owned()],
+        slots: 1,
+    });
+
+    (shipyard, settlement, resources)
+}
+
+#[test]
+fn missing_materials_delay_construction() {
+    let (mut shipyard, mut settlement, resources) = setup();
+    let task = ShipyardTask::Construction(ship_class());
+
+    // half of the materials are in stock
+    assert_eq!(task.materials_available(&settlement), 0.5);
+    assert_eq!(task.time(&settlement), 6);
+
+    let materials_price = task.materials_price(&settlement, &resources);
+    assert!(materials_price > 0);
+    shipyard.order(task, &mut settlement, &resources);
+
+    assert_eq!(shipyard.orders[0].time_remaining, 6);
+    assert_eq!(settlement.resources.get("Wood"), Some(&20));
+    assert_eq!(settlement.silver, 1000 + materials_price);
+}
+
+#[test]
+fn works_on_orders_in_slots() {
+    let (mut shipyard, mut settlement, resources) = setup();
+    settlement.resources.insert("Pitch".to_owned(), 100);
+
+    shipyard.order(
+        ShipyardTask::Construction(ship_class()),
+        &mut settlement,
+        &resources,
+    );
+    shipyard.order(
+        ShipyardTask::Construction(ship_class()),
+        &mut settlement,
+        &resources,
+    );
+    assert_eq!(shipyard.status(0), ShipyardOrderStatus::InProgress(4));
+    assert_eq!(shipyard.status(1), ShipyardOrderStatus::Waiting);
+
+    for _ in 0..4 {
+        shipyard.work();
+    }
+    assert_eq!(shipyard.status(0), ShipyardOrderStatus::Ready);
+    assert_eq!(shipyard.status(1), ShipyardOrderStatus::InProgress(4));
+
+    let ship = shipyard.pick_up(0);
+    assert_eq!(ship.class.name, "Small Ship");
+    assert_eq!(shipyard.orders.len(), 1);
+}
@@ -1,4 +1,4 @@
-use crate::{population::Population, resources::Resource, settlement::Settlement, COIN_NAME};
+use crate::{population::Population, resources::Resource, settlement::Settlement};
 use std::{fs::File, io::BufReader};
 
 pub fn debug_settlements() {
@@ -30,22 +30,7 @@ pub fn debug_settlements() {
         for population in settlement.populations.iter() {
             let population = populations.iter().find(|p| p.name == *population).unwrap();
             for production in population.production.iter() {
-                let yearly = 2 * production.amount.growth
-                    + production.amount.summer
-                    + production.amount.harvest
-                    + 2 * production.amount.winter;
-
-                let base_price = if production.resource == COIN_NAME {
-                    1
-                } else {
-                    resources
-                        .iter()
-                        .find(|i| i.name == production.resource)
-                        .unwrap()
-                        .base_price
-                };
-
-                output += yearly * base_price;
+                output += production.yearly_value(&resources);
             }
         }
 
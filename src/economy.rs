@@ -0,0 +1,48 @@
+use crate::{
+    game_rng::GameRngLabel,
+    merchant, population, price_calculator,
+    settlement::{self, SettlementLabel},
+};
+use bevy::prelude::*;
+
+/// Systems advancing the economy of all settlements each season. Shared by the
+/// game and the headless simulation, so balancing runs the real thing.
+pub struct EconomyPlugin;
+
+impl Plugin for EconomyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<price_calculator::AveragePrices>()
+            .add_system(population::population_production.label(SettlementLabel::Production))
+            .add_system(
+                settlement::consume_resources::consume_resources
+                    .label(SettlementLabel::Consumption)
+                    .after(SettlementLabel::Production),
+            )
+            .add_system(
+                settlement::cap_resources::cap_resources
+                    .label(SettlementLabel::CapResources)
+                    .after(SettlementLabel::Consumption),
+            )
+            .add_system(
+                merchant::sail_convoys
+                    .label(GameRngLabel::Trade)
+                    .after(SettlementLabel::CapResources),
+            )
+            .add_system(
+                settlement::demographics::demographics
+                    .label(GameRngLabel::Demographics)
+                    .after(GameRngLabel::Trade),
+            )
+            .add_system(price_calculator::average_prices.after(GameRngLabel::Demographics))
+            .add_system(
+                settlement::track_prices::track_prices
+                    .label(SettlementLabel::TrackPrices)
+                    .after(GameRngLabel::Demographics),
+            )
+            .add_system(
+                settlement::modifiers::age_modifiers
+                    .after(SettlementLabel::TrackPrices)
+                    .after(GameRngLabel::Demographics),
+            );
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{
+    game_events::{ConditionContext, GameEventAction},
+    types::Player,
+    COIN_NAME,
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests;
+
+/// silver and goods the player gives up when choosing an action
+#[derive(Debug, Deserialize, Default, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct GameEventCost {
+    #[serde(default)]
+    pub silver: u32,
+    #[serde(default)]
+    pub resources: HashMap<String, u32>,
+}
+
+impl GameEventCost {
+    pub fn is_free(&self) -> bool {
+        self.silver == 0 && self.resources.values().all(|amount| *amount == 0)
+    }
+
+    fn resources_sorted(&self) -> Vec<(&String, &u32)> {
+        let mut resources: Vec<(&String, &u32)> = self.resources.iter().collect();
+        resources.sort();
+        resources
+    }
+
+    pub fn can_afford(&self, player: &Player) -> bool {
+        player.silver >= self.silver
+            && self
+                .resources
+                .iter()
+                .all(|(resource, amount)| player.resource_count(resource) >= *amount)
+    }
+
+    pub fn pay(&self, player: &mut Player) {
+        player.silver -= u32::min(self.silver, player.silver);
+        for (resource, amount) in self.resources_sorted() {
+            player.remove_resource(resource, *amount);
+        }
+    }
+}
+
+impl std::fmt::Display for GameEventCost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = vec![];
+        if self.silver > 0 {
+            parts.push(format!("{} {}", self.silver, COIN_NAME));
+        }
+        for (resource, amount) in self.resources_sorted() {
+            if *amount > 0 {
+                parts.push(format!("{} {}", amount, resource));
+            }
+        }
+
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+impl GameEventAction {
+    /// why the action can not be chosen right now, none if it can
+    pub fn unavailable_reason(&self, context: &ConditionContext) -> Option<String> {
+        if !self.cost.can_afford(context.player) {
+            return Some(format!("You can not afford {}", self.cost));
+        }
+
+        let unmet: Vec<String> = self
+            .requires
+            .iter()
+            .filter(|condition| !condition.is_met(context))
+            .map(|condition| condition.to_string())
+            .collect();
+        if unmet.is_empty() {
+            return None;
+        }
+
+        Some(
+            self.hint
+                .clone()
+                .unwrap_or_else(|| format!("Requires {}", unmet.join(" and "))),
+        )
+    }
+}
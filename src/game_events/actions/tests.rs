@@ -0,0 +1,73 @@
+use super::*;
+use crate::{game_events::GameEventsState, game_time::GameTime};
+use bevy::prelude::*;
+
+fn action(yaml: &str) -> GameEventAction {
+    serde_yaml::from_str(yaml).unwrap()
+}
+
+fn reason(action: &GameEventAction, player: &Player) -> Option<String> {
+    let time = GameTime::default();
+    let state = GameEventsState::default();
+    let context = ConditionContext {
+        player,
+        time: &time,
+        state: &state,
+        settlements: vec![],
+        location: None,
+    };
+
+    action.unavailable_reason(&context)
+}
+
+#[test]
+fn costs_need_to_be_affordable() {
+    let action = action(
+        "
+label: Bribe the harbour master
+cost:
+  silver: 50
+",
+    );
+    let mut player = Player {
+        silver: 40,
+        ..default()
+    };
+
+    assert_eq!(
+        reason(&action, &player),
+        Some("You can not afford 50 Silver".to_owned())
+    );
+
+    player.silver = 60;
+    assert_eq!(reason(&action, &player), None);
+
+    action.cost.pay(&mut player);
+    assert_eq!(player.silver, 10);
+}
+
+#[test]
+fn requirements_explain_themselves() {
+    let mut action = action(
+        "
+label: Pay the ransom
+requires:
+  - type: silver
+    min: 100
+  - type: convoy_size
+    max: 3
+",
+    );
+    let player = Player::default();
+
+    assert_eq!(
+        reason(&action, &player),
+        Some("Requires at least 100 Silver".to_owned())
+    );
+
+    action.hint = Some("You are too poor".to_owned());
+    assert_eq!(
+        reason(&action, &player),
+        Some("You are too poor".to_owned())
+    );
+}
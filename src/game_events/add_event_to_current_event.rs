@@ -22,6 +22,10 @@ pub fn add_event_to_current_event(
                     AddEventToCurrentEventPosition::Front => {
                         state.current_events.insert(0, event.id.to_owned())
                     }
+                    AddEventToCurrentEventPosition::Next => {
+                        let index = usize::min(1, state.current_events.len());
+                        state.current_events.insert(index, event.id.to_owned())
+                    }
                     AddEventToCurrentEventPosition::End => {
                         state.current_events.push(event.id.to_owned())
                     }
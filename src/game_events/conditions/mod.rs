@@ -0,0 +1,258 @@
+use crate::{
+    game_events::GameEventsState, game_time::GameTime, settlement::Settlement, types::Player,
+    COIN_NAME,
+};
+use bevy::prelude::*;
+use serde::Deserialize;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SeasonName {
+    Winter,
+    Growth,
+    Summer,
+    Harvest,
+}
+
+impl SeasonName {
+    fn matches(&self, time: &GameTime) -> bool {
+        match self {
+            SeasonName::Winter => time.is_winter_season(),
+            SeasonName::Growth => time.is_growth_season(),
+            SeasonName::Summer => time.is_summer_season(),
+            SeasonName::Harvest => time.is_harvest_season(),
+        }
+    }
+}
+
+/// state of the player and the world an event requires, bounds are inclusive and may be left out
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum GameEventCondition {
+    Silver {
+        min: Option<u32>,
+        max: Option<u32>,
+    },
+    /// units of a resource stowed anywhere in the convoy
+    Resource {
+        resource: String,
+        min: Option<u32>,
+        max: Option<u32>,
+    },
+    ConvoySize {
+        min: Option<usize>,
+        max: Option<usize>,
+    },
+    /// health between 0 and 1 of the weakest ship in the convoy
+    ShipHealth {
+        min: Option<f32>,
+        max: Option<f32>,
+    },
+    Season {
+        seasons: Vec<SeasonName>,
+    },
+    Year {
+        min: Option<i16>,
+        max: Option<i16>,
+    },
+    /// supply of a settlement, the one the convoy is in if none is named
+    Supply {
+        resource: String,
+        settlement: Option<String>,
+        min: Option<u32>,
+        max: Option<u32>,
+    },
+    EventSeen {
+        id: String,
+    },
+    /// story flag set by an earlier event
+    Flag {
+        flag: String,
+    },
+    /// story counter, which is zero until an event changes it
+    Counter {
+        counter: String,
+        min: Option<i32>,
+        max: Option<i32>,
+    },
+    Not {
+        condition: Box<GameEventCondition>,
+    },
+    Any {
+        conditions: Vec<GameEventCondition>,
+    },
+}
+
+/// everything conditions of events are checked against
+pub struct ConditionContext<'a> {
+    pub player: &'a Player,
+    pub time: &'a GameTime,
+    pub state: &'a GameEventsState,
+    pub settlements: Vec<&'a Settlement>,
+    /// settlement the convoy is currently in
+    pub location: Option<&'a Settlement>,
+}
+
+impl<'a> ConditionContext<'a> {
+    pub fn new(
+        player: &'a Player,
+        time: &'a GameTime,
+        state: &'a GameEventsState,
+        settlements: impl Iterator<Item = (Entity, &'a Settlement)>,
+    ) -> Self {
+        let mut location = None;
+        let settlements = settlements
+            .map(|(entity, settlement)| {
+                if player.location == Some(entity) {
+                    location = Some(settlement);
+                }
+                settlement
+            })
+            .collect();
+
+        Self {
+            player,
+            time,
+            state,
+            settlements,
+            location,
+        }
+    }
+}
+
+fn within<T: PartialOrd>(value: T, min: Option<T>, max: Option<T>) -> bool {
+    min.map_or(true, |min| value >= min) && max.map_or(true, |max| value <= max)
+}
+
+impl GameEventCondition {
+    pub fn is_met(&self, context: &ConditionContext) -> bool {
+        match self {
+            GameEventCondition::Silver { min, max } => within(context.player.silver, *min, *max),
+            GameEventCondition::Resource { resource, min, max } => {
+                within(context.player.resource_count(resource), *min, *max)
+            }
+            GameEventCondition::ConvoySize { min, max } => {
+                within(context.player.convoy.len(), *min, *max)
+            }
+            GameEventCondition::ShipHealth { min, max } => context
+                .player
+                .convoy
+                .iter()
+                .map(|ship| ship.health())
+                .reduce(f32::min)
+                .map_or(false, |health| within(health, *min, *max)),
+            GameEventCondition::Season { seasons } => {
+                seasons.iter().any(|season| season.matches(context.time))
+            }
+            GameEventCondition::Year { min, max } => within(context.time.year, *min, *max),
+            GameEventCondition::Supply {
+                resource,
+                settlement,
+                min,
+                max,
+            } => {
+                let settlement = match settlement {
+                    Some(name) => context
+                        .settlements
+                        .iter()
+                        .copied()
+                        .find(|settlement| &settlement.name == name),
+                    None => context.location,
+                };
+
+                settlement.map_or(false, |settlement| {
+                    let supply = settlement.resources.get(resource).copied().unwrap_or(0);
+                    within(supply, *min, *max)
+                })
+            }
+            GameEventCondition::EventSeen { id } => context.state.seen_events.contains(id),
+            GameEventCondition::Flag { flag } => context.state.has_flag(flag),
+            GameEventCondition::Counter { counter, min, max } => {
+                within(context.state.counter(counter), *min, *max)
+            }
+            GameEventCondition::Not { condition } => !condition.is_met(context),
+            GameEventCondition::Any { conditions } => {
+                conditions.iter().any(|condition| condition.is_met(context))
+            }
+        }
+    }
+}
+
+fn bounds_text<T: std::fmt::Display>(what: &str, min: &Option<T>, max: &Option<T>) -> String {
+    match (min, max) {
+        (Some(min), Some(max)) => format!("between {} and {} {}", min, max, what),
+        (Some(min), None) => format!("at least {} {}", min, what),
+        (None, Some(max)) => format!("at most {} {}", max, what),
+        (None, None) => format!("any {}", what),
+    }
+}
+
+fn percent(share: &Option<f32>) -> Option<String> {
+    share.map(|share| format!("{:.0}%", share * 100.))
+}
+
+impl std::fmt::Display for GameEventCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameEventCondition::Silver { min, max } => {
+                write!(f, "{}", bounds_text(COIN_NAME, min, max))
+            }
+            GameEventCondition::Resource { resource, min, max } => {
+                write!(f, "{} in the cargo", bounds_text(resource, min, max))
+            }
+            GameEventCondition::ConvoySize { min, max } => {
+                write!(f, "{} in the convoy", bounds_text("ships", min, max))
+            }
+            GameEventCondition::ShipHealth { min, max } => write!(
+                f,
+                "{} of the weakest ship",
+                bounds_text("health", &percent(min), &percent(max))
+            ),
+            GameEventCondition::Season { seasons } => {
+                let seasons: Vec<String> = seasons
+                    .iter()
+                    .map(|season| format!("{:?}", season).to_lowercase())
+                    .collect();
+                write!(f, "the {} season", seasons.join(" or "))
+            }
+            GameEventCondition::Year { min, max } => match (min, max) {
+                (Some(min), Some(max)) => write!(f, "the years {} to {}", min, max),
+                (Some(min), None) => write!(f, "the year {} or later", min),
+                (None, Some(max)) => write!(f, "the year {} or earlier", max),
+                (None, None) => write!(f, "any year"),
+            },
+            GameEventCondition::Supply {
+                resource,
+                settlement,
+                min,
+                max,
+            } => write!(
+                f,
+                "{} in {}",
+                bounds_text(resource, min, max),
+                settlement.as_deref().unwrap_or("the port")
+            ),
+            GameEventCondition::EventSeen { id } => write!(f, "having experienced {}", id),
+            GameEventCondition::Flag { flag } => write!(f, "{}", flag),
+            GameEventCondition::Counter { counter, min, max } => {
+                write!(f, "{}", bounds_text(counter, min, max))
+            }
+            GameEventCondition::Not { condition } => write!(f, "not {}", condition),
+            GameEventCondition::Any { conditions } => {
+                let conditions: Vec<String> = conditions
+                    .iter()
+                    .map(|condition| condition.to_string())
+                    .collect();
+                write!(f, "{}", conditions.join(" or "))
+            }
+        }
+    }
+}
+
+/// whether all of the conditions are met, which is the case if there are none
+pub fn all_met(conditions: &[GameEventCondition], context: &ConditionContext) -> bool {
+    conditions.iter().all(|condition| condition.is_met(context))
+}
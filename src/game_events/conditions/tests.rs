@@ -0,0 +1,104 @@
+use super::*;
+use crate::types::{Ship, ShipClass};
+
+fn ship() -> Ship {
+    Ship::new(&ShipClass::test_class("Small Ship", 20))
+}
+
+fn check(condition: &str, player: &Player, time: &GameTime) -> bool {
+    let condition: GameEventCondition = serde_yaml::from_str(condition).unwrap();
+    let mut state = GameEventsState {
+        seen_events: ["storm.light".to_owned()].into_iter().collect(),
+        ..default()
+    };
+    state.set_flag("patron.met");
+    state.set_counter("feud", 2);
+    let settlement = Settlement::test_city("Histria", &[], &[("Grain", 30)]);
+    let context = ConditionContext {
+        player,
+        time,
+        state: &state,
+        settlements: vec![&settlement],
+        location: None,
+    };
+
+    condition.is_met(&context)
+}
+
+#[test]
+fn checks_player_bounds() {
+    let mut player = Player {
+        silver: 40,
+        convoy: vec![ship()],
+        ..default()
+    };
+    let time = GameTime::default();
+
+    assert!(check("{type: silver, max: 50}", &player, &time));
+    assert!(!check("{type: silver, min: 50}", &player, &time));
+    assert!(check("{type: convoy_size, min: 1, max: 1}", &player, &time));
+
+    player.convoy[0].damage = 15;
+    assert!(check("{type: ship_health, max: 0.5}", &player, &time));
+    player.convoy.clear();
+    assert!(!check("{type: ship_health, max: 0.5}", &player, &time));
+}
+
+#[test]
+fn checks_time_supply_and_seen_events() {
+    let player = Player::default();
+    let time = GameTime { season: 5, year: 3 };
+
+    assert!(check("{type: season, seasons: [winter]}", &player, &time));
+    assert!(!check(
+        "{type: season, seasons: [summer, harvest]}",
+        &player,
+        &time
+    ));
+    assert!(check("{type: year, min: 3}", &player, &time));
+    assert!(check(
+        "{type: supply, resource: Grain, settlement: Histria, min: 20}",
+        &player,
+        &time
+    ));
+    assert!(!check(
+        "{type: supply, resource: Grain, min: 20}",
+        &player,
+        &time
+    ));
+    assert!(check("{type: event_seen, id: storm.light}", &player, &time));
+    assert!(check(
+        "{type: not, condition: {type: event_seen, id: pirates.encounter}}",
+        &player,
+        &time
+    ));
+    assert!(check(
+        "{type: any, conditions: [{type: year, max: 1}, {type: silver, max: 0}]}",
+        &player,
+        &time
+    ));
+}
+
+#[test]
+fn checks_story_flags_and_counters() {
+    let player = Player::default();
+    let time = GameTime::default();
+
+    assert!(check("{type: flag, flag: patron.met}", &player, &time));
+    assert!(!check("{type: flag, flag: patron.angry}", &player, &time));
+    assert!(check(
+        "{type: counter, counter: feud, min: 2}",
+        &player,
+        &time
+    ));
+    assert!(check(
+        "{type: counter, counter: debts, max: 0}",
+        &player,
+        &time
+    ));
+}
+
+#[test]
+fn rejects_unknown_fields() {
+    assert!(serde_yaml::from_str::<GameEventCondition>("{type: silver, maximum: 5}").is_err());
+}
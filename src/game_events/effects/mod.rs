@@ -0,0 +1,47 @@
+use crate::{
+    game_events::{ShipTarget, SupplyEffect},
+    settlement::Settlement,
+    types::Ship,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// adds a signed amount without dropping below zero
+pub fn apply_change(value: u32, amount: i32) -> u32 {
+    (value as i64 + amount as i64).clamp(0, u32::MAX as i64) as u32
+}
+
+impl ShipTarget {
+    /// index of the ship in the convoy the target picks
+    pub fn select(&self, convoy: &[Ship]) -> Option<usize> {
+        convoy
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| match self {
+                ShipTarget::MostCargo => a.cargo_used().cmp(&b.cargo_used()),
+                ShipTarget::Weakest => b.health().total_cmp(&a.health()),
+                ShipTarget::Largest => a.resource_space().cmp(&b.resource_space()),
+            })
+            .map(|(index, _)| index)
+    }
+}
+
+pub fn repair(ship: &mut Ship, amount: u32) {
+    ship.damage -= u32::min(amount, ship.damage);
+}
+
+impl SupplyEffect {
+    pub fn apply(&self, settlement: &mut Settlement) {
+        let supply = settlement
+            .resources
+            .get(&self.resource)
+            .copied()
+            .unwrap_or(0);
+        let changed = (supply as f32 * self.share).round() as i32 + self.amount;
+
+        settlement
+            .resources
+            .insert(self.resource.to_owned(), apply_change(supply, changed));
+    }
+}
@@ -0,0 +1,31 @@
+use super::*;
+
+#[test]
+fn changes_never_drop_below_zero() {
+    assert_eq!(apply_change(10, 5), 15);
+    assert_eq!(apply_change(10, -5), 5);
+    assert_eq!(apply_change(10, -50), 0);
+}
+
+#[test]
+fn supply_shocks_change_shares_and_amounts() {
+    let mut settlement = Settlement::test_city("Histria", &[], &[("Grain", 30)]);
+
+    let effect = SupplyEffect {
+        settlement: None,
+        resource: "Grain".to_owned(),
+        share: -0.5,
+        amount: 0,
+    };
+    effect.apply(&mut settlement);
+    assert_eq!(settlement.resources.get("Grain"), Some(&15));
+
+    let effect = SupplyEffect {
+        settlement: None,
+        resource: "Fish".to_owned(),
+        share: 0.5,
+        amount: 20,
+    };
+    effect.apply(&mut settlement);
+    assert_eq!(settlement.resources.get("Fish"), Some(&20));
+}
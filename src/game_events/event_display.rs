@@ -1,5 +1,10 @@
 use crate::{
-    game_events::{AddEventToCurrentEvent, GameEvent, GameEventsState},
+    game_events::{
+        AddEventToCurrentEvent, ConditionContext, GameEvent, GameEventsState, TriggerEventEffect,
+    },
+    game_time::GameTime,
+    settlement::Settlement,
+    types::Player,
     ui::large_button,
 };
 use bevy::prelude::*;
@@ -13,24 +18,39 @@ pub struct EventTextures {
     textures: HashMap<String, bevy_egui::egui::TextureId>,
 }
 
+#[allow(clippy::type_complexity)]
 pub fn event_display(
-    mut egui_context: ResMut<EguiContext>,
+    ui_resources: (Res<Windows>, ResMut<EguiContext>),
     events: Option<Res<HashMap<String, GameEvent>>>,
     mut state: ResMut<GameEventsState>,
     mut textures: Local<EventTextures>,
-    windows: Res<Windows>,
     asset_server: Res<AssetServer>,
-    mut add_event: EventWriter<AddEventToCurrentEvent>,
+    writers: (
+        EventWriter<AddEventToCurrentEvent>,
+        EventWriter<TriggerEventEffect>,
+    ),
+    world: (ResMut<Player>, Res<GameTime>, Query<(Entity, &Settlement)>),
 ) {
     if events.is_none() {
         return;
     };
 
+    let (windows, mut egui_context) = ui_resources;
+    let (mut add_event, mut effects) = writers;
+    let (mut player, time, settlements) = world;
     let events = events.unwrap();
     let window = windows.primary();
 
     if let Some(id) = state.current_events.first() {
         let event: &GameEvent = events.get(id).unwrap();
+        let unavailable: Vec<Option<String>> = {
+            let context = ConditionContext::new(&player, &time, &state, settlements.iter());
+            event
+                .actions
+                .iter()
+                .map(|action| action.unavailable_reason(&context))
+                .collect()
+        };
 
         if !textures.textures.contains_key(&event.image) {
             let image = asset_server.load(&format!("images/{}.jpg", event.image));
@@ -58,10 +78,28 @@ pub fn event_display(
                 bevy_egui::egui::TopBottomPanel::bottom("footer")
                     .frame(Frame::none())
                     .show_inside(ui, |ui| {
-                        for action in &event.actions {
+                        for (action, reason) in event.actions.iter().zip(unavailable.iter()) {
                             let w = ui.available_width();
-                            if large_button(ui, w, &action.label).clicked() {
+                            let mut button = ui
+                                .add_enabled_ui(reason.is_none(), |ui| {
+                                    large_button(ui, w, &action.label)
+                                })
+                                .inner;
+                            if let Some(reason) = reason {
+                                button = button.on_disabled_hover_text(reason);
+                            }
+                            if !action.cost.is_free() {
+                                button = button.on_hover_text(format!("Costs {}", action.cost));
+                            }
+
+                            if button.clicked() {
                                 state.current_events.remove(0);
+                                action.cost.pay(&mut player);
+                                for effect in &action.effects {
+                                    effects.send(TriggerEventEffect {
+                                        effect: effect.clone(),
+                                    });
+                                }
                                 if let Some(id) = &action.trigger_event.clone() {
                                     add_event
                                         .send(AddEventToCurrentEvent::new_to_front(id.clone()));
@@ -1,13 +1,39 @@
 use crate::{
-    game_events::{GameEventEffect, TriggerEventEffect},
+    game_events::{
+        effects::{apply_change, repair},
+        AddEventToCurrentEvent, GameEventEffect, GameEventsState, PirateFleet, TriggerEventEffect,
+    },
+    game_rng::GameRng,
+    resources::Resource,
+    settlement::Settlement,
     types::Player,
 };
 use bevy::prelude::*;
-use rand::{thread_rng, Rng};
+use rand::Rng;
+
+/// the named settlement or the one the convoy is in
+fn find_settlement<'a>(
+    settlements: &'a mut Query<(Entity, &mut Settlement)>,
+    name: &Option<String>,
+    location: Option<Entity>,
+) -> Option<Mut<'a, Settlement>> {
+    settlements
+        .iter_mut()
+        .find(|(entity, settlement)| match name {
+            Some(name) => &settlement.name == name,
+            None => Some(*entity) == location,
+        })
+        .map(|(_, settlement)| settlement)
+}
 
 pub fn event_effect_handler(
     mut effects: EventReader<TriggerEventEffect>,
     mut player: Option<ResMut<Player>>,
+    mut rng: ResMut<GameRng>,
+    mut state: ResMut<GameEventsState>,
+    mut add_event: EventWriter<AddEventToCurrentEvent>,
+    resources: Option<Res<Vec<Resource>>>,
+    mut settlements: Query<(Entity, &mut Settlement)>,
 ) {
     if player.is_none() {
         return;
@@ -23,8 +49,7 @@ pub fn event_effect_handler(
                         return;
                     };
 
-                    let mut random = thread_rng();
-                    let index = random.gen_range(0..player.convoy.len());
+                    let index = rng.gen_range(0..player.convoy.len());
 
                     let mut ship = &mut player.convoy[index];
                     ship.damage += damage.amount;
@@ -34,6 +59,128 @@ pub fn event_effect_handler(
                         ship.damage += damage.amount;
                     }
                 }
+                GameEventEffect::DamageShip(damage) => {
+                    if let Some(index) = damage.target.select(&player.convoy) {
+                        player.convoy[index].damage += damage.amount;
+                    }
+                }
+                GameEventEffect::RepairAllShips(repaired) => {
+                    for ship in player.convoy.iter_mut() {
+                        repair(ship, repaired.amount);
+                    }
+                }
+                GameEventEffect::RepairShip(repaired) => {
+                    if let Some(index) = repaired.target.select(&player.convoy) {
+                        repair(&mut player.convoy[index], repaired.amount);
+                    }
+                }
+                GameEventEffect::LoseSilver(loss) => {
+                    let lost = (player.silver as f32 * loss.share).ceil() as u32;
+                    player.silver -= u32::min(lost, player.silver);
+                }
+                GameEventEffect::LoseCargo(loss) => {
+                    let mut cargo: Vec<(String, u32)> = player.cargo().into_iter().collect();
+                    cargo.sort();
+
+                    for (resource, amount) in cargo {
+                        let lost = (amount as f32 * loss.share).ceil() as u32;
+                        player.remove_resource(&resource, lost);
+                    }
+                }
+                GameEventEffect::ChangeSilver(change) => {
+                    player.silver = apply_change(player.silver, change.amount);
+                }
+                GameEventEffect::AddCargo(cargo) => {
+                    let resource = resources.as_ref().and_then(|resources| {
+                        resources
+                            .iter()
+                            .find(|resource| resource.name == cargo.resource)
+                    });
+
+                    match resource {
+                        Some(resource) => {
+                            let stowed = player.add_resource(resource, cargo.amount);
+                            if stowed < cargo.amount {
+                                log::info!(
+                                    "no space for {} {}",
+                                    cargo.amount - stowed,
+                                    cargo.resource
+                                );
+                            }
+                        }
+                        None => log::warn!("unknown resource {}", cargo.resource),
+                    }
+                }
+                GameEventEffect::RemoveCargo(cargo) => {
+                    player.remove_resource(&cargo.resource, cargo.amount);
+                }
+                GameEventEffect::MovePlayer(destination) => {
+                    let target = settlements
+                        .iter()
+                        .find(|(_, settlement)| settlement.name == destination.settlement)
+                        .map(|(entity, settlement)| {
+                            let position = &settlement.position;
+                            (entity, Vec2::new(position.x as f32, position.y as f32))
+                        });
+
+                    match target {
+                        Some((entity, position)) => player.update_position(position, Some(entity)),
+                        None => log::warn!("unknown settlement {}", destination.settlement),
+                    }
+                }
+                GameEventEffect::ChangeSupply(supply) => {
+                    if let Some(mut settlement) =
+                        find_settlement(&mut settlements, &supply.settlement, player.location)
+                    {
+                        supply.apply(&mut settlement);
+                    }
+                }
+                GameEventEffect::ChangeSettlementSilver(change) => {
+                    if let Some(mut settlement) =
+                        find_settlement(&mut settlements, &change.settlement, player.location)
+                    {
+                        settlement.silver = apply_change(settlement.silver, change.amount);
+                    }
+                }
+                GameEventEffect::ModifySettlement(modify) => {
+                    if let Some(mut settlement) =
+                        find_settlement(&mut settlements, &modify.settlement, player.location)
+                    {
+                        settlement.add_modifier(modify.modifier.clone());
+                    }
+                }
+                GameEventEffect::SetFlag(flag) => state.set_flag(&flag.flag),
+                GameEventEffect::ClearFlag(flag) => state.clear_flag(&flag.flag),
+                GameEventEffect::SetCounter(counter) => {
+                    state.set_counter(&counter.counter, counter.amount)
+                }
+                GameEventEffect::ChangeCounter(counter) => {
+                    state.change_counter(&counter.counter, counter.amount)
+                }
+                GameEventEffect::SpawnPirates(spawn) => {
+                    state.pirates = Some(PirateFleet {
+                        strength: spawn.strength * rng.gen_range(0.5..1.5),
+                    });
+                }
+                GameEventEffect::ResolvePirates(resolve) => {
+                    let chance = match state.pirates.take() {
+                        Some(fleet) => fleet.success_chance(player, resolve.tactic),
+                        None => {
+                            log::warn!("resolving pirates without an encounter");
+                            1.
+                        }
+                    };
+
+                    let next = if rng.gen_range(0.0..1.0) < chance {
+                        Some(&resolve.success)
+                    } else {
+                        resolve.failure.as_ref()
+                    };
+
+                    if let Some(id) = next {
+                        add_event.send(AddEventToCurrentEvent::new_next(id.clone()));
+                    }
+                }
             }
         }
     }
@@ -8,10 +8,13 @@ pub fn event_travel(
     mut events: EventReader<PlayerTravelEvent>,
     mut triggers: EventWriter<TriggerEvent>,
 ) {
-    for _ in events.iter() {
-        triggers.send(TriggerEvent {
-            event: GameEventTriggerEventName::Travel,
-            scope: None,
-        });
+    // every season at sea may bring its own events
+    for event in events.iter() {
+        for _ in 0..event.seasons {
+            triggers.send(TriggerEvent {
+                event: GameEventTriggerEventName::Travel,
+                scope: None,
+            });
+        }
     }
 }
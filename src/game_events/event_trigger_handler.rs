@@ -1,6 +1,14 @@
-use crate::game_events::{AddEventToCurrentEvent, GameEvent, GameEventsState, TriggerEvent};
+use crate::{
+    game_events::{
+        AddEventToCurrentEvent, ConditionContext, GameEvent, GameEventsState, TriggerEvent,
+    },
+    game_rng::GameRng,
+    game_time::GameTime,
+    settlement::Settlement,
+    types::Player,
+};
 use bevy::prelude::*;
-use rand::{seq::SliceRandom, thread_rng, Rng};
+use rand::{seq::SliceRandom, Rng};
 use std::collections::HashMap;
 
 pub fn event_trigger_handler(
@@ -8,41 +16,46 @@ pub fn event_trigger_handler(
     events: Option<Res<HashMap<String, GameEvent>>>,
     state: Res<GameEventsState>,
     mut add_event: EventWriter<AddEventToCurrentEvent>,
+    mut rng: ResMut<GameRng>,
+    world: (
+        Option<Res<Player>>,
+        Res<GameTime>,
+        Query<(Entity, &Settlement)>,
+    ),
 ) {
-    if events.is_none() {
+    let (player, time, settlements) = world;
+    if events.is_none() || player.is_none() {
         return;
     };
     let events = events.unwrap();
+    let player = player.unwrap();
+    let context = ConditionContext::new(&player, &time, &state, settlements.iter());
+
+    // hash map order differs between runs, the chances need to be rolled in a fixed one
+    let mut all_events: Vec<&GameEvent> = events.values().collect();
+    all_events.sort_by(|a, b| a.id.cmp(&b.id));
 
     for trigger in triggers.iter() {
-        let mut random = thread_rng();
-        let mut events: Vec<&GameEvent> = events
+        let mut events: Vec<&GameEvent> = all_events
             .iter()
-            .filter_map(|(_, event)| {
-                if let Some(event_trigger) = &event.trigger {
-                    if event_trigger.once && state.seen_events.contains(&event.id) {
-                        return None;
-                    }
+            .copied()
+            .filter_map(|event| {
+                if !event.can_trigger(trigger.event, &trigger.scope, &context) {
+                    return None;
+                }
 
-                    if event_trigger.event != trigger.event || event_trigger.scope != trigger.scope
-                    {
+                let chance = event.trigger.as_ref().and_then(|trigger| trigger.chance);
+                if let Some(chance) = chance {
+                    if rng.gen_range(0.0..1.0) > chance {
                         return None;
                     }
-
-                    if let Some(chance) = event_trigger.chance {
-                        if random.gen_range(0.0..1.0) > chance {
-                            return None;
-                        }
-                    }
-
-                    Some(event)
-                } else {
-                    None
                 }
+
+                Some(event)
             })
             .collect();
 
-        events.shuffle(&mut thread_rng());
+        events.shuffle(&mut *rng);
 
         if let Some(event) = events.first() {
             add_event.send(AddEventToCurrentEvent::new(event.id.clone()));
@@ -1,21 +1,42 @@
-use crate::game_state::RunningState;
+use crate::{game_rng::GameRngLabel, game_state::RunningState, settlement::SettlementModifier};
 use bevy::prelude::*;
-use serde::Deserialize;
-use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 
+mod actions;
 mod add_event_to_current_event;
+mod conditions;
+mod effects;
 mod event_display;
 mod event_effect_handler;
 mod event_shipwreck;
 mod event_travel;
 mod event_trigger_handler;
 mod event_visit_settlement;
+mod pirates;
+mod story;
+#[cfg(test)]
+mod tests;
+
+pub use actions::GameEventCost;
+pub use conditions::{all_met, ConditionContext, GameEventCondition};
+pub use pirates::{PirateFleet, PirateTactic, ResolvePiratesEffect, SpawnPiratesEffect};
+pub use story::{CounterEffect, FlagEffect};
 
 #[derive(Debug, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct GameEventAction {
     pub label: String,
     pub trigger_event: Option<String>,
+    /// state that has to hold for the action to be available
+    #[serde(default)]
+    pub requires: Vec<GameEventCondition>,
+    /// shown when the requirements are not met instead of listing them
+    pub hint: Option<String>,
+    #[serde(default)]
+    pub cost: GameEventCost,
+    #[serde(default)]
+    pub effects: Vec<GameEventEffect>,
 }
 
 #[derive(Debug, Deserialize, PartialEq, Eq, Clone, Copy)]
@@ -34,6 +55,9 @@ pub struct GameEventTriggerCondition {
     #[serde(default)]
     pub once: bool,
     pub chance: Option<f32>,
+    /// state of the player and the world that has to hold for the event to happen
+    #[serde(default)]
+    pub conditions: Vec<GameEventCondition>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -42,11 +66,100 @@ pub struct DamageEffect {
     pub amount: u32,
 }
 
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ShipTarget {
+    MostCargo,
+    Weakest,
+    Largest,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct DamageShipEffect {
+    pub amount: u32,
+    pub target: ShipTarget,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct LoseShareEffect {
+    /// share between 0 and 1 that is lost
+    pub share: f32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SilverEffect {
+    /// negative amounts are lost, but never more than there is
+    pub amount: i32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct CargoEffect {
+    pub resource: String,
+    pub amount: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct MovePlayerEffect {
+    pub settlement: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SupplyEffect {
+    /// the settlement the convoy is in if none is named
+    pub settlement: Option<String>,
+    pub resource: String,
+    /// share of the current supply that is gained or, if negative, lost
+    #[serde(default)]
+    pub share: f32,
+    #[serde(default)]
+    pub amount: i32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementSilverEffect {
+    /// the settlement the convoy is in if none is named
+    pub settlement: Option<String>,
+    pub amount: i32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementModifierEffect {
+    /// the settlement the convoy is in if none is named
+    pub settlement: Option<String>,
+    pub modifier: SettlementModifier,
+}
+
 #[derive(Deserialize, Debug, Clone)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum GameEventEffect {
     DamageAnyShip(DamageEffect),
     DamageAllShips(DamageEffect),
+    DamageShip(DamageShipEffect),
+    RepairAllShips(DamageEffect),
+    RepairShip(DamageShipEffect),
+    LoseSilver(LoseShareEffect),
+    LoseCargo(LoseShareEffect),
+    ChangeSilver(SilverEffect),
+    AddCargo(CargoEffect),
+    RemoveCargo(CargoEffect),
+    MovePlayer(MovePlayerEffect),
+    ChangeSupply(SupplyEffect),
+    ChangeSettlementSilver(SettlementSilverEffect),
+    ModifySettlement(SettlementModifierEffect),
+    SetFlag(FlagEffect),
+    ClearFlag(FlagEffect),
+    SetCounter(CounterEffect),
+    ChangeCounter(CounterEffect),
+    SpawnPirates(SpawnPiratesEffect),
+    ResolvePirates(ResolvePiratesEffect),
 }
 
 #[derive(Debug, Deserialize)]
@@ -62,10 +175,66 @@ pub struct GameEvent {
     pub actions: Vec<GameEventAction>,
 }
 
-#[derive(Default)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct GameEventsState {
     pub current_events: Vec<String>,
     pub seen_events: HashSet<String>,
+    #[serde(default)]
+    pub pirates: Option<PirateFleet>,
+    /// story flags set by events, e.g. to continue a storyline later
+    #[serde(default)]
+    pub flags: HashSet<String>,
+    #[serde(default)]
+    pub counters: HashMap<String, i32>,
+}
+
+impl GameEvent {
+    /// whether the event strikes at random during a voyage, travel events
+    /// without a chance are story events that only depend on their conditions
+    pub fn is_travel_hazard(&self) -> bool {
+        matches!(
+            &self.trigger,
+            Some(trigger) if trigger.event == GameEventTriggerEventName::Travel
+                && trigger.scope.is_none()
+                && trigger.chance.is_some()
+        )
+    }
+
+    /// whether a trigger could bring up the event right now, before its chance is rolled
+    pub fn can_trigger(
+        &self,
+        event: GameEventTriggerEventName,
+        scope: &Option<String>,
+        context: &ConditionContext,
+    ) -> bool {
+        match &self.trigger {
+            Some(trigger) => {
+                trigger.event == event
+                    && &trigger.scope == scope
+                    && !(trigger.once && context.state.seen_events.contains(&self.id))
+                    && all_met(&trigger.conditions, context)
+            }
+            None => false,
+        }
+    }
+}
+
+/// chance that at least one hazardous event strikes during a voyage
+pub fn travel_risk(
+    events: &HashMap<String, GameEvent>,
+    seasons: u32,
+    context: &ConditionContext,
+) -> f32 {
+    let safe_season = events
+        .values()
+        .filter(|event| {
+            event.is_travel_hazard()
+                && event.can_trigger(GameEventTriggerEventName::Travel, &None, context)
+        })
+        .filter_map(|event| event.trigger.as_ref().and_then(|trigger| trigger.chance))
+        .fold(1., |safe, chance| safe * (1. - chance));
+
+    1. - f32::powi(safe_season, seasons as i32)
 }
 
 #[derive(Debug)]
@@ -82,6 +251,8 @@ pub struct TriggerEventEffect {
 #[derive(Debug, Copy, Clone)]
 pub enum AddEventToCurrentEventPosition {
     Front,
+    /// right after the event currently shown
+    Next,
     End,
 }
 
@@ -105,6 +276,13 @@ impl AddEventToCurrentEvent {
             position: AddEventToCurrentEventPosition::Front,
         }
     }
+
+    pub fn new_next(id: String) -> Self {
+        Self {
+            id,
+            position: AddEventToCurrentEventPosition::Next,
+        }
+    }
 }
 
 pub struct GameEventsPlugin;
@@ -119,9 +297,17 @@ impl Plugin for GameEventsPlugin {
                 SystemSet::on_update(RunningState::Paused)
                     .with_system(event_display::event_display),
             )
-            .add_system(event_trigger_handler::event_trigger_handler)
+            .add_system(
+                event_trigger_handler::event_trigger_handler
+                    .label(GameRngLabel::EventTrigger)
+                    .after(GameRngLabel::Demographics),
+            )
             .add_system(add_event_to_current_event::add_event_to_current_event)
-            .add_system(event_effect_handler::event_effect_handler)
+            .add_system(
+                event_effect_handler::event_effect_handler
+                    .label(GameRngLabel::EventEffect)
+                    .after(GameRngLabel::EventTrigger),
+            )
             .add_system(event_travel::event_travel)
             .add_system(event_visit_settlement::event_visit_settlement)
             .add_system(event_shipwreck::event_shipwreck);
@@ -0,0 +1,74 @@
+use crate::types::Player;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// chance of a lone, fully manned and unharmed ship to escape
+const FLEE_CHANCE: f32 = 0.6;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SpawnPiratesEffect {
+    /// strength of the fleet, a match for a crew of this many sailors
+    pub strength: f32,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PirateTactic {
+    Flee,
+    Fight,
+    Tribute,
+    Surrender,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ResolvePiratesEffect {
+    pub tactic: PirateTactic,
+    /// event following when the tactic works out
+    pub success: String,
+    /// event following when it does not
+    pub failure: Option<String>,
+}
+
+/// hostile fleet the convoy currently faces
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PirateFleet {
+    pub strength: f32,
+}
+
+impl PirateFleet {
+    /// Chance of the tactic to work out against the fleet. Fighting depends on
+    /// the crew and the state of the ships, fleeing gets harder for large,
+    /// damaged or undermanned convoys.
+    pub fn success_chance(&self, player: &Player, tactic: PirateTactic) -> f32 {
+        match tactic {
+            PirateTactic::Flee => {
+                let ships = usize::max(player.convoy.len(), 1) as f32;
+                let health = if player.convoy.is_empty() {
+                    1.
+                } else {
+                    player.convoy.iter().map(|ship| ship.health()).sum::<f32>() / ships
+                };
+
+                FLEE_CHANCE * player.manned() * health / ships.sqrt()
+            }
+            PirateTactic::Fight => {
+                let strength: f32 = player
+                    .convoy
+                    .iter()
+                    .map(|ship| ship.crew as f32 * ship.health())
+                    .sum();
+
+                if strength + self.strength <= 0. {
+                    0.
+                } else {
+                    strength / (strength + self.strength)
+                }
+            }
+            PirateTactic::Tribute | PirateTactic::Surrender => 1.,
+        }
+    }
+}
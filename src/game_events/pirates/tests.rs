@@ -0,0 +1,43 @@
+use super::*;
+use crate::types::{Ship, ShipClass};
+use bevy::prelude::*;
+
+fn ship(crew: u32) -> Ship {
+    Ship::new(&ShipClass {
+        crew,
+        ..ShipClass::test_class("Small Ship", 20)
+    })
+}
+
+#[test]
+fn fighting_depends_on_crew_and_health() {
+    let fleet = PirateFleet { strength: 8. };
+    let mut player = Player {
+        convoy: vec![ship(4), ship(4)],
+        ..default()
+    };
+
+    assert_eq!(fleet.success_chance(&player, PirateTactic::Fight), 0.5);
+
+    player.convoy[0].damage = 10;
+    player.convoy[1].damage = 10;
+    assert_eq!(fleet.success_chance(&player, PirateTactic::Fight), 4. / 12.);
+}
+
+#[test]
+fn fleeing_is_harder_for_large_convoys() {
+    let fleet = PirateFleet { strength: 8. };
+    let mut player = Player {
+        convoy: vec![ship(4)],
+        ..default()
+    };
+
+    assert_eq!(fleet.success_chance(&player, PirateTactic::Flee), 0.6);
+
+    player.convoy = vec![ship(4), ship(4), ship(4), ship(4)];
+    assert_eq!(fleet.success_chance(&player, PirateTactic::Flee), 0.3);
+
+    player.convoy[0].crew = 0;
+    player.convoy[1].crew = 0;
+    assert_eq!(fleet.success_chance(&player, PirateTactic::Flee), 0.15);
+}
@@ -0,0 +1,47 @@
+use crate::game_events::GameEventsState;
+use serde::Deserialize;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct FlagEffect {
+    pub flag: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct CounterEffect {
+    pub counter: String,
+    /// value the counter is set to or changed by
+    pub amount: i32,
+}
+
+impl GameEventsState {
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags.contains(flag)
+    }
+
+    pub fn set_flag(&mut self, flag: &str) {
+        self.flags.insert(flag.to_owned());
+    }
+
+    pub fn clear_flag(&mut self, flag: &str) {
+        self.flags.remove(flag);
+    }
+
+    /// value of a counter, zero if it was never touched
+    pub fn counter(&self, counter: &str) -> i32 {
+        self.counters.get(counter).copied().unwrap_or(0)
+    }
+
+    pub fn set_counter(&mut self, counter: &str, value: i32) {
+        self.counters.insert(counter.to_owned(), value);
+    }
+
+    pub fn change_counter(&mut self, counter: &str, amount: i32) {
+        let value = self.counter(counter).saturating_add(amount);
+        self.set_counter(counter, value);
+    }
+}
@@ -0,0 +1,26 @@
+use super::*;
+
+#[test]
+fn flags_can_be_set_and_cleared() {
+    let mut state = GameEventsState::default();
+    assert!(!state.has_flag("patron.met"));
+
+    state.set_flag("patron.met");
+    assert!(state.has_flag("patron.met"));
+
+    state.clear_flag("patron.met");
+    assert!(!state.has_flag("patron.met"));
+}
+
+#[test]
+fn counters_start_at_zero() {
+    let mut state = GameEventsState::default();
+    assert_eq!(state.counter("feud"), 0);
+
+    state.change_counter("feud", 2);
+    state.change_counter("feud", -3);
+    assert_eq!(state.counter("feud"), -1);
+
+    state.set_counter("feud", 5);
+    assert_eq!(state.counter("feud"), 5);
+}
@@ -0,0 +1,84 @@
+use super::*;
+use crate::{game_time::GameTime, types::Player};
+
+fn events() -> HashMap<String, GameEvent> {
+    let events: Vec<GameEvent> = serde_yaml::from_str(
+        "
+- id: storm
+  trigger:
+    event: travel
+    chance: 0.5
+  title: Storm
+  text: The storm leads to damage in follow up events.
+  image: storm
+  actions:
+    - label: Hold on!
+      trigger_event: storm.damage
+- id: sea_serpent
+  trigger:
+    event: travel
+    once: true
+    chance: 0.5
+  title: Sea serpent
+  text: Only ever seen once.
+  image: storm
+  actions: []
+- id: debt_collector
+  trigger:
+    event: travel
+    chance: 0.5
+    conditions:
+      - type: silver
+        max: 0
+  title: Debt collector
+  text: Only shows up for the broke.
+  image: storm
+  actions: []
+- id: first_voyage
+  trigger:
+    event: travel
+  title: First voyage
+  text: A story event without a chance.
+  image: storm
+  actions: []
+",
+    )
+    .unwrap();
+
+    events
+        .into_iter()
+        .map(|event| (event.id.clone(), event))
+        .collect()
+}
+
+fn risk(player: &Player, state: &GameEventsState, seasons: u32) -> f32 {
+    let time = GameTime::default();
+    let context = ConditionContext {
+        player,
+        time: &time,
+        state,
+        settlements: vec![],
+        location: None,
+    };
+
+    travel_risk(&events(), seasons, &context)
+}
+
+#[test]
+fn travel_risk_counts_hazards_that_can_trigger() {
+    let player = Player {
+        silver: 100,
+        ..default()
+    };
+    let mut state = GameEventsState::default();
+
+    // storm and sea serpent
+    assert_eq!(risk(&player, &state, 1), 0.75);
+    assert_eq!(risk(&player, &state, 2), 1. - 0.25 * 0.25);
+
+    state.seen_events.insert("sea_serpent".to_owned());
+    assert_eq!(risk(&player, &state, 1), 0.5);
+
+    let broke = Player::default();
+    assert_eq!(risk(&broke, &state, 1), 0.75);
+}
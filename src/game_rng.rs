@@ -0,0 +1,83 @@
+use crate::game_time::GameTime;
+use bevy::prelude::*;
+use rand::{rngs::StdRng, thread_rng, Rng, RngCore, SeedableRng};
+
+/// Systems drawing from [`GameRng`] run in this order, otherwise the parallel
+/// executor could interleave their draws differently between two runs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SystemLabel)]
+pub enum GameRngLabel {
+    Trade,
+    Demographics,
+    EventTrigger,
+    EventEffect,
+    Shipwreck,
+}
+
+/// The only source of randomness of the simulation. The same seed and the
+/// same player actions always yield the same game.
+pub struct GameRng {
+    seed: u64,
+    /// the seed came from the command line or a save game, settings keep it
+    fixed: bool,
+    rng: StdRng,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            fixed: false,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// a seed chosen on the command line, which the settings may not replace
+    pub fn fixed(seed: u64) -> Self {
+        Self {
+            fixed: true,
+            ..Self::new(seed)
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn is_fixed(&self) -> bool {
+        self.fixed
+    }
+
+    /// Restarts the stream from the seed and the game time of a loaded save
+    /// game, so loading the same save always plays out alike.
+    pub fn resume(&mut self, seed: u64, time: &GameTime) {
+        let offset = (time.year as i64 * 6 + time.season as i64) as u64;
+
+        self.seed = seed;
+        self.fixed = true;
+        self.rng = StdRng::seed_from_u64(seed ^ offset.wrapping_mul(0x9e37_79b9_7f4a_7c15));
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::new(thread_rng().gen())
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
@@ -5,6 +5,7 @@ use bevy_egui::{
 };
 
 use crate::{game_state::RunningState, ui::enabled_color};
+use serde::{Deserialize, Serialize};
 
 pub struct GameTimeAdvancedEvent {
     pub time: GameTime,
@@ -12,7 +13,7 @@ pub struct GameTimeAdvancedEvent {
 
 pub struct GameTimeAdvanceEvent;
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct GameTime {
     pub season: i8,
     pub year: i16,
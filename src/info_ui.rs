@@ -1,7 +1,12 @@
-use crate::{types::Player, COIN_NAME};
+use crate::{
+    resources::Resource,
+    save_game::{LoadGameEvent, SaveGameEvent},
+    types::{Hold, Player},
+    COIN_NAME,
+};
 use bevy::prelude::*;
 use bevy_egui::{
-    egui::{self, Align2},
+    egui::{self, Align2, Ui},
     EguiContext,
 };
 
@@ -16,12 +21,21 @@ pub struct DevState {
 pub fn info_ui(
     mut egui_context: ResMut<EguiContext>,
     player: Option<ResMut<Player>>,
-    mut convoy_open: Local<bool>,
+    convoy: (Local<bool>, Option<Res<Vec<Resource>>>),
+    mut save_events: EventWriter<SaveGameEvent>,
+    mut load_events: EventWriter<LoadGameEvent>,
     #[cfg(debug_assertions)] mut dev: Local<DevState>,
+    #[cfg(debug_assertions)] rng: Res<crate::game_rng::GameRng>,
     #[cfg(debug_assertions)] mut trigger_event: EventWriter<
         crate::game_events::AddEventToCurrentEvent,
     >,
 ) {
+    let (mut convoy_open, resources) = convoy;
+    let resources = resources
+        .as_deref()
+        .map(|resources| resources.as_slice())
+        .unwrap_or_default();
+
     #[allow(unused_mut)]
     if let Some(mut player) = player {
         #[cfg(debug_assertions)]
@@ -66,6 +80,14 @@ pub fn info_ui(
                         *convoy_open = !*convoy_open;
                     }
 
+                    if ui.small_button("Save").clicked() {
+                        save_events.send(SaveGameEvent);
+                    }
+
+                    if ui.small_button("Load").clicked() {
+                        load_events.send(LoadGameEvent);
+                    }
+
                     #[cfg(debug_assertions)]
                     if ui.small_button("DEV").clicked() {
                         dev.open = !dev.open;
@@ -73,16 +95,48 @@ pub fn info_ui(
                 });
 
                 if *convoy_open {
-                    for transport in &player.convoy {
-                        ui.label(if transport.damage > 0 {
-                            format!(
-                                " - {} ({}%)",
-                                transport,
-                                (transport.health() * 100.).floor()
-                            )
-                        } else {
-                            format!(" - {}", transport)
+                    for hold in player.holds() {
+                        let cargo = format!(
+                            "cargo {}/{}",
+                            player.hold_used(hold),
+                            player.hold_capacity(hold)
+                        );
+                        ui.label(match hold {
+                            Hold::Base => format!(" - {}, {}", player.hold_name(hold), cargo),
+                            Hold::Ship(index) => {
+                                let transport = &player.convoy[index];
+                                let crew =
+                                    format!("crew {}/{}", transport.crew, transport.class.crew);
+                                if transport.damage > 0 {
+                                    format!(
+                                        " - {} ({}%), {}, {}",
+                                        player.hold_name(hold),
+                                        (transport.health() * 100.).floor(),
+                                        crew,
+                                        cargo
+                                    )
+                                } else {
+                                    format!(" - {}, {}, {}", player.hold_name(hold), crew, cargo)
+                                }
+                            }
                         });
+                        cargo_rows(ui, &mut player, hold, resources);
+                    }
+
+                    let upkeep = &player.upkeep;
+                    ui.label(format!(
+                        "Last season: {} {} wages, {} provisions ({} {})",
+                        upkeep.wages,
+                        COIN_NAME,
+                        upkeep.provisions,
+                        upkeep.provisions_bought,
+                        COIN_NAME
+                    ));
+                    if upkeep.hired > 0 {
+                        ui.label(format!("{} sailors hired", upkeep.hired));
+                    }
+                    if upkeep.deserted > 0 {
+                        ui.label(format!("{} sailors deserted", upkeep.deserted));
                     }
                 }
 
@@ -101,8 +155,56 @@ pub fn info_ui(
                         dev.event_open = !dev.event_open;
                         dev.event_name = "".to_owned();
                     }
+
+                    ui.label(format!("Seed: {}", rng.seed()));
+                }
+            });
+    }
+}
+
+/// lists the cargo of a hold with a menu to move it to another hold
+fn cargo_rows(ui: &mut Ui, player: &mut Player, hold: Hold, resources: &[Resource]) {
+    let mut cargo: Vec<(String, u32)> = player
+        .hold(hold)
+        .iter()
+        .map(|(name, amount)| (name.clone(), *amount))
+        .collect();
+    cargo.sort();
+
+    for (name, amount) in cargo {
+        let resource = match resources.iter().find(|resource| resource.name == name) {
+            Some(resource) => resource,
+            None => continue,
+        };
+
+        ui.horizontal(|ui| {
+            ui.label(format!("     {} {}", amount, name));
+            ui.menu_button("move", |ui| {
+                for target in player.holds() {
+                    if target == hold || !player.can_stow(target, resource) {
+                        continue;
+                    }
+
+                    let space = player.hold_space_left(target);
+                    ui.horizontal(|ui| {
+                        ui.label(format!("{} ({} free)", player.hold_name(target), space));
+                        if ui
+                            .add_enabled(space > 0, egui::Button::new("one"))
+                            .clicked()
+                        {
+                            player.move_cargo(resource, hold, target, 1);
+                        }
+                        if ui
+                            .add_enabled(space > 0, egui::Button::new("all"))
+                            .clicked()
+                        {
+                            player.move_cargo(resource, hold, target, amount);
+                            ui.close_menu();
+                        }
+                    });
                 }
             });
+        });
     }
 }
 
@@ -3,6 +3,7 @@ use crate::{
     game_time::{GameTime, GameTimeAdvanceEvent},
     population::Population,
     resources::Resource,
+    save_game::PendingLoadGame,
     Settings,
 };
 use bevy::prelude::*;
@@ -14,7 +15,13 @@ pub fn initialize_game_time(
     settings: Option<Res<Settings>>,
     game_time: Res<GameTime>,
     mut events: EventWriter<GameTimeAdvanceEvent>,
+    pending_load: Option<Res<PendingLoadGame>>,
 ) {
+    // a save game restores the economy, so it must not be warmed up
+    if pending_load.is_some() {
+        return;
+    }
+
     if settlement_handle.is_none()
         && resources.is_some()
         && populations.is_some()
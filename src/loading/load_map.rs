@@ -3,6 +3,7 @@ use crate::{
     camera::GameCamera,
     map::{
         constants::{TILEMAP_SIZE, Z_FEATURES, Z_GROUND},
+        navigation::NavigationGrid,
         types::{MapTileType, Overlay},
         MapSize,
     },
@@ -186,6 +187,14 @@ pub fn load_map(
                 }
             }
 
+            let mut water = vec![];
+            for y in 0..map_size.height {
+                for x in 0..map_size.width {
+                    water.push(map_loader.map_tile(x as i32, y as i32) == Some(MapTileType::Water));
+                }
+            }
+            commands.insert_resource(NavigationGrid::new(map_size.width, map_size.height, water));
+
             commands
                 .entity(ground_tilemap_entity)
                 .insert_bundle(TilemapBundle {
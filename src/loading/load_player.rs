@@ -1,6 +1,6 @@
 use crate::{
     settlement::Settlement,
-    types::{Player, Ship, ShipSize},
+    types::{Player, Ship, ShipClass},
     Settings,
 };
 use bevy::prelude::*;
@@ -10,12 +10,19 @@ pub fn load_player(
     settlements: Query<(Entity, &Settlement)>,
     settings: Option<Res<Settings>>,
     player: Option<ResMut<Player>>,
+    ship_classes: Option<Res<Vec<ShipClass>>>,
 ) {
-    if player.is_some() {
+    if player.is_some() || ship_classes.is_none() {
         return;
     }
+    let ship_classes = ship_classes.unwrap();
 
     if let Some(settings) = settings {
+        let start_ship = ship_classes
+            .iter()
+            .find(|class| class.name == settings.start_ship)
+            .expect("start ship class to exist");
+
         if let Some((entity, settlement)) = settlements
             .iter()
             .find(|(_, settlement)| settlement.name == settings.start_settlement)
@@ -25,7 +32,7 @@ pub fn load_player(
                 position: Vec2::new(settlement.position.x as f32, settlement.position.y as f32),
                 location: Some(entity),
                 location_marker_need_update: true,
-                convoy: vec![Ship::new(ShipSize::Small)],
+                convoy: vec![Ship::new(start_ship)],
                 ..default()
             };
 
@@ -0,0 +1,37 @@
+use crate::{
+    map::navigation::{NavigationGrid, SeaRoutes},
+    settlement::Settlement,
+};
+use bevy::prelude::*;
+
+pub fn load_sea_routes(
+    mut commands: Commands,
+    grid: Option<Res<NavigationGrid>>,
+    sea_routes: Option<Res<SeaRoutes>>,
+    settlements: Query<&Settlement>,
+) {
+    if grid.is_none() || sea_routes.is_some() || settlements.is_empty() {
+        return;
+    }
+    let grid = grid.unwrap();
+
+    log::debug!("calculating sea routes");
+    let settlements: Vec<&Settlement> = settlements.iter().collect();
+    let mut sea_routes = SeaRoutes::default();
+
+    for (index, from) in settlements.iter().enumerate() {
+        for to in settlements.iter().skip(index + 1) {
+            let route = grid.route(
+                UVec2::new(from.position.x, from.position.y),
+                UVec2::new(to.position.x, to.position.y),
+            );
+
+            match route {
+                Some(route) => sea_routes.insert(&from.name, &to.name, route),
+                None => log::warn!("no sea route between {} and {}", from.name, to.name),
+            }
+        }
+    }
+
+    commands.insert_resource(sea_routes);
+}
@@ -1,4 +1,4 @@
-use crate::{loading::GameEvents, Settings};
+use crate::{game_rng::GameRng, loading::GameEvents, Settings};
 use bevy::prelude::*;
 
 pub fn load_settings(
@@ -6,11 +6,19 @@ pub fn load_settings(
     settings_handle: Option<Res<Handle<Settings>>>,
     mut settings: ResMut<Assets<Settings>>,
     server: Res<AssetServer>,
+    mut rng: ResMut<GameRng>,
 ) {
     if let Some(settings_handle) = settings_handle {
         if let Some(settings) = settings.remove(settings_handle.id) {
             log::debug!("loading settings data");
 
+            if let Some(seed) = settings.seed {
+                if !rng.is_fixed() {
+                    *rng = GameRng::new(seed);
+                }
+            }
+            log::info!("game seed {}", rng.seed());
+
             let events: Vec<Handle<GameEvents>> = settings
                 .events
                 .iter()
@@ -33,7 +33,10 @@ pub fn load_settlements(
                         for building in settlement.buildings.iter_mut() {
                             let mut entity = commands.spawn();
                             let entity = match &building.building_type {
-                                BuildingType::Shipyard => entity.insert(Shipyard::default()).id(),
+                                BuildingType::Shipyard(info) => {
+                                    let shipyard: Shipyard = info.clone().into();
+                                    entity.insert(shipyard).id()
+                                }
                                 BuildingType::Temple(info) => {
                                     let temple: Temple = info.clone().into();
                                     entity.insert(temple).id()
@@ -0,0 +1,17 @@
+use super::Ships;
+use bevy::prelude::*;
+
+pub fn load_ships(
+    mut commands: Commands,
+    ships_handle: Option<Res<Handle<Ships>>>,
+    mut ships: ResMut<Assets<Ships>>,
+) {
+    if let Some(ships_handle) = ships_handle {
+        if let Some(ships) = ships.remove(ships_handle.id) {
+            log::debug!("loading ships data");
+
+            commands.insert_resource(ships.0);
+            commands.remove_resource::<Handle<Ships>>()
+        }
+    }
+}
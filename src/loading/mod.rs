@@ -1,6 +1,14 @@
 use crate::{
-    deities::Deity, game_events::GameEvent, game_state::LoadingState, game_time::GameTime,
-    population::Population, resources::Resource, settlement::Settlement, types::Player, Settings,
+    deities::Deity,
+    game_events::GameEvent,
+    game_state::LoadingState,
+    game_time::GameTime,
+    map::navigation::SeaRoutes,
+    population::Population,
+    resources::Resource,
+    settlement::Settlement,
+    types::{Player, ShipClass},
+    Settings,
 };
 use bevy::{prelude::*, reflect::TypeUuid};
 use bevy_ecs_tilemap::prelude::*;
@@ -13,8 +21,10 @@ mod load_map;
 mod load_player;
 mod load_populations;
 mod load_resources;
+mod load_sea_routes;
 mod load_settings;
 mod load_settlements;
+mod load_ships;
 
 #[derive(Default)]
 pub struct AssetsLoading(Vec<HandleUntyped>);
@@ -39,6 +49,10 @@ pub struct Deities(Vec<Deity>);
 #[uuid = "599d5626-6452-49b3-b5a1-7b3292071509"]
 pub struct GameEvents(Vec<GameEvent>);
 
+#[derive(Debug, Deserialize, TypeUuid)]
+#[uuid = "018fe08a-321e-4487-9e78-400d9c2c0890"]
+pub struct Ships(Vec<ShipClass>);
+
 pub struct MapImage(Handle<Image>);
 pub struct FeaturesTilemap((Entity, TilemapId));
 
@@ -50,6 +64,7 @@ pub fn setup(mut commands: Commands, server: Res<AssetServer>) {
     let populations: Handle<Populations> = server.load("game.populations");
     let settings: Handle<Settings> = server.load("game.settings");
     let deities: Handle<Deities> = server.load("game.deities");
+    let ships: Handle<Ships> = server.load("game.ships");
 
     log::debug!("requesting assets");
     commands.insert_resource(map_image);
@@ -57,6 +72,7 @@ pub fn setup(mut commands: Commands, server: Res<AssetServer>) {
     commands.insert_resource(resources);
     commands.insert_resource(populations);
     commands.insert_resource(deities);
+    commands.insert_resource(ships);
     commands.insert_resource(settings);
 }
 
@@ -72,6 +88,8 @@ pub fn transition(
         Option<Res<Handle<Settings>>>,
         Option<Res<Vec<Handle<GameEvents>>>>,
         Res<GameTime>,
+        Option<Res<SeaRoutes>>,
+        Option<Res<Handle<Ships>>>,
     ),
     player: Option<Res<Player>>,
 ) {
@@ -84,6 +102,8 @@ pub fn transition(
         settings_handle,
         events_handle,
         game_time,
+        sea_routes,
+        ships_handle,
     ) = res;
 
     if settlement_handle.is_none()
@@ -93,7 +113,9 @@ pub fn transition(
         && resources_handle.is_none()
         && settings_handle.is_none()
         && events_handle.is_none()
+        && ships_handle.is_none()
         && player.is_some()
+        && sea_routes.is_some()
         && game_time.is_initialized()
     {
         log::info!("all resources fully loaded");
@@ -111,8 +133,10 @@ impl Plugin for LoadingPlugin {
                     .with_system(transition)
                     .with_system(load_map::load_map)
                     .with_system(load_settlements::load_settlements)
+                    .with_system(load_ships::load_ships)
                     .with_system(load_populations::load_populations)
                     .with_system(load_resources::load_resources)
+                    .with_system(load_sea_routes::load_sea_routes)
                     .with_system(load_deities::load_deities)
                     .with_system(load_events::load_events)
                     .with_system(load_settings::load_settings)
@@ -4,28 +4,31 @@ use bevy::{prelude::*, reflect::TypeUuid, render::texture::ImageSettings};
 use bevy_common_assets::yaml::YamlAssetPlugin;
 use bevy_ecs_tilemap::prelude::*;
 use bevy_egui::EguiPlugin;
-use clap::Command;
+use clap::{value_parser, Arg, Command};
 use dotenv::dotenv;
 use serde::Deserialize;
-use settlement::SettlementLabel;
 
 mod building;
 mod camera;
 mod debug_populations;
 mod debug_settlements;
 mod deities;
+mod economy;
 mod game_events;
+mod game_rng;
 mod game_state;
 mod game_time;
 mod info_ui;
 mod loading;
 mod map;
+mod merchant;
 mod player;
 mod population;
 mod price_calculator;
 mod resources;
+mod save_game;
 mod settlement;
-mod trader;
+mod simulate;
 mod types;
 mod ui;
 
@@ -39,14 +42,110 @@ pub struct Settings {
     min_silver: types::CalculatedPopulationValue,
     start_settlement: String,
     start_silver: u32,
+    start_ship: String,
     max_multipliers: types::SeasonalAmount<f32>,
+    consumption_multipliers: types::SeasonalAmount<f32>,
+    demographics: settlement::demographics::DemographicsSettings,
+    upkeep: player::UpkeepSettings,
+    wear: player::WearSettings,
+    insurance: player::InsuranceSettings,
     cap_percentage: f32,
     events: HashSet<String>,
+    #[serde(default)]
+    seed: Option<u64>,
+}
+
+#[cfg(test)]
+impl Settings {
+    /// settings for tests, consuming the whole demand every season
+    pub fn test_settings() -> Self {
+        serde_yaml::from_str(
+            "
+min_silver:
+  Population: 10
+max_silver:
+  Population: 100
+start_settlement: Histria
+start_silver: 100
+start_ship: Small Ship
+max_multipliers:
+  growth: 1.0
+  summer: 1.0
+  harvest: 1.0
+  winter: 1.0
+cap_percentage: 0.5
+consumption_multipliers:
+  growth: 1.0
+  summer: 1.0
+  harvest: 1.0
+  winter: 0.5
+demographics:
+  food:
+    - Grain
+  growth_threshold: 0.5
+  decline_threshold: -0.4
+  migration_distance: 30
+  min_population: 3
+upkeep:
+  wage: 2
+  hiring_fee: 5
+  sailors_per_provision: 4
+  provisions:
+    - Grain
+  desertion: 0.5
+events: []
+wear:
+  per_tile: 0.05
+  winter: 2.0
+  aging: 0.01
+insurance:
+  voyage_premium: 0.04
+  season_premium: 0.07
+  winter: 2.0
+  payout: 0.8
+",
+        )
+        .unwrap()
+    }
 }
 
 fn cli() -> Command {
     Command::new("pondos")
         .about("a game about trading")
+        .arg(
+            Arg::new("load")
+                .long("load")
+                .value_name("FILE")
+                .help("Continues a previously saved game"),
+        )
+        .arg(
+            Arg::new("seed")
+                .long("seed")
+                .value_name("SEED")
+                .value_parser(value_parser!(u64))
+                .global(true)
+                .help("Seeds all randomness to replay a game"),
+        )
+        .subcommand(
+            Command::new("simulate")
+                .about("Runs the economy without a window and writes every season to a CSV file")
+                .arg(
+                    Arg::new("years")
+                        .long("years")
+                        .value_name("YEARS")
+                        .value_parser(value_parser!(u32))
+                        .default_value("10")
+                        .help("Years to simulate after the warm-up"),
+                )
+                .arg(
+                    Arg::new("output")
+                        .long("output")
+                        .short('o')
+                        .value_name("FILE")
+                        .default_value("simulation.csv")
+                        .help("CSV file to write"),
+                ),
+        )
         .subcommand(
             Command::new("debug")
                 .subcommand(
@@ -77,8 +176,27 @@ fn main() {
         std::process::exit(0);
     }
 
+    if let Some(("simulate", cmd)) = matches.subcommand() {
+        simulate::simulate(
+            *cmd.get_one::<u32>("years").unwrap(),
+            cmd.get_one::<String>("output").unwrap(),
+            cmd.get_one::<u64>("seed").copied(),
+        );
+        std::process::exit(0);
+    }
+
     let mut app = App::new();
 
+    app.insert_resource(match matches.get_one::<u64>("seed") {
+        Some(seed) => game_rng::GameRng::fixed(*seed),
+        None => game_rng::GameRng::default(),
+    });
+
+    if let Some(file) = matches.get_one::<String>("load") {
+        app.insert_resource(save_game::SaveGameFile(file.to_owned()))
+            .insert_resource(save_game::PendingLoadGame);
+    }
+
     #[cfg(not(target_family = "wasm"))]
     app.insert_resource(WindowDescriptor {
         width: 1680.0,
@@ -97,7 +215,6 @@ fn main() {
         197.0 / 255.0,
         185.0 / 255.0,
     )))
-    .init_resource::<price_calculator::AveragePrices>()
     .add_event::<game_time::GameTimeAdvancedEvent>()
     .add_event::<game_time::GameTimeAdvanceEvent>()
     .add_event::<ui::CloseSettlementUIEvent>()
@@ -116,6 +233,7 @@ fn main() {
         "populations",
     ]))
     .add_plugin(YamlAssetPlugin::<loading::Deities>::new(&["deities"]))
+    .add_plugin(YamlAssetPlugin::<loading::Ships>::new(&["ships"]))
     .add_plugin(YamlAssetPlugin::<loading::GameEvents>::new(&["events"]))
     .add_plugin(YamlAssetPlugin::<Settings>::new(&["settings"]))
     .add_plugin(TilemapPlugin)
@@ -128,10 +246,8 @@ fn main() {
     .add_plugin(settlement::SettlementPlugin)
     .add_plugin(building::BuildingPlugin)
     .add_plugin(game_time::GameTimePlugin)
-    .add_system(population::population_production)
-    .add_system(price_calculator::average_prices)
-    .add_system(settlement::cap_resources::cap_resources.label(SettlementLabel::CapResources))
-    .add_system(trader::trade_merchant.after(SettlementLabel::CapResources))
+    .add_plugin(save_game::SaveGamePlugin)
+    .add_plugin(economy::EconomyPlugin)
     .add_system(info_ui::info_ui)
     .add_startup_system(ui::color_mode)
     .add_startup_system(info_ui::show_game_version)
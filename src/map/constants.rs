@@ -23,4 +23,5 @@ pub const TILEMAP_ROWS: usize = 6;
 
 pub const Z_GROUND: f32 = -0.03;
 pub const Z_FEATURES: f32 = -0.02;
+pub const Z_ROUTE: f32 = -0.015;
 pub const Z_MARKER: f32 = -0.01;
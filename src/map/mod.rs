@@ -9,11 +9,14 @@ use bevy_ecs_tilemap::prelude::*;
 use iyes_loopless::prelude::ConditionSet;
 
 mod on_exit;
+mod route_overlay;
 mod settlement_click;
 mod update_cursor_pos;
+mod update_merchant_markers;
 mod update_player_position;
 
 pub mod constants;
+pub mod navigation;
 pub mod types;
 
 #[derive(Clone, Copy)]
@@ -83,7 +86,11 @@ pub struct MapPlugin;
 impl Plugin for MapPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<CursorPos>()
+            .init_resource::<route_overlay::VoyageTrail>()
+            .init_resource::<route_overlay::RouteOverlay>()
             .add_system(update_player_position::update_player_position)
+            .add_system(route_overlay::record_voyage_trail)
+            .add_system(route_overlay::update_route_overlay)
             .add_system(update_cursor_pos::update_cursor_pos)
             .add_system(update_tiles)
             .add_system_set(SystemSet::on_exit(GameState::Map).with_system(on_exit::on_exit))
@@ -93,6 +100,7 @@ impl Plugin for MapPlugin {
                     .run_in_bevy_state(LoadingState::Loaded)
                     .with_system(pan_orbit_camera)
                     .with_system(settlement_click::settlement_click)
+                    .with_system(update_merchant_markers::update_merchant_markers)
                     .into(),
             );
     }
@@ -0,0 +1,147 @@
+use bevy::prelude::*;
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+#[cfg(test)]
+mod tests;
+
+const STRAIGHT_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+
+/// Which tiles of the map ships can sail on.
+pub struct NavigationGrid {
+    width: u32,
+    height: u32,
+    water: Vec<bool>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SeaRoute {
+    /// tiles from start to destination, both included
+    pub tiles: Vec<UVec2>,
+    /// length of the route in tiles
+    pub length: f32,
+}
+
+impl SeaRoute {
+    pub fn seasons(&self, tiles_per_season: f32) -> u32 {
+        f32::max((self.length / tiles_per_season).ceil(), 1.) as u32
+    }
+
+    fn reversed(&self) -> Self {
+        Self {
+            tiles: self.tiles.iter().rev().copied().collect(),
+            length: self.length,
+        }
+    }
+}
+
+impl NavigationGrid {
+    pub fn new(width: u32, height: u32, water: Vec<bool>) -> Self {
+        assert_eq!(water.len(), (width * height) as usize);
+
+        Self {
+            width,
+            height,
+            water,
+        }
+    }
+
+    pub fn is_water(&self, x: i32, y: i32) -> bool {
+        x >= 0
+            && y >= 0
+            && x < self.width as i32
+            && y < self.height as i32
+            && self.water[self.index(x as u32, y as u32)]
+    }
+
+    fn index(&self, x: u32, y: u32) -> usize {
+        (x + y * self.width) as usize
+    }
+
+    /// Shortest route over water between two tiles. Start and destination
+    /// may be on land, as settlements lie on the coast.
+    pub fn route(&self, from: UVec2, to: UVec2) -> Option<SeaRoute> {
+        let passable = |x: i32, y: i32| self.is_water(x, y) || IVec2::new(x, y) == to.as_ivec2();
+
+        let mut costs: Vec<u32> = vec![u32::MAX; self.water.len()];
+        let mut previous: Vec<Option<UVec2>> = vec![None; self.water.len()];
+        let mut queue = BinaryHeap::new();
+
+        costs[self.index(from.x, from.y)] = 0;
+        queue.push(Reverse((0, from.x, from.y)));
+
+        while let Some(Reverse((cost, x, y))) = queue.pop() {
+            let tile = UVec2::new(x, y);
+            if tile == to {
+                break;
+            }
+            if cost > costs[self.index(x, y)] {
+                continue;
+            }
+
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+                    if (dx == 0 && dy == 0) || !passable(nx, ny) {
+                        continue;
+                    }
+
+                    let step = if dx != 0 && dy != 0 {
+                        // do not cut corners of the coast
+                        if !passable(x as i32 + dx, y as i32) || !passable(x as i32, y as i32 + dy)
+                        {
+                            continue;
+                        }
+                        DIAGONAL_COST
+                    } else {
+                        STRAIGHT_COST
+                    };
+
+                    let index = self.index(nx as u32, ny as u32);
+                    if cost + step < costs[index] {
+                        costs[index] = cost + step;
+                        previous[index] = Some(tile);
+                        queue.push(Reverse((cost + step, nx as u32, ny as u32)));
+                    }
+                }
+            }
+        }
+
+        let cost = costs[self.index(to.x, to.y)];
+        if cost == u32::MAX {
+            return None;
+        }
+
+        let mut tiles = vec![to];
+        while let Some(tile) =
+            previous[self.index(tiles.last().unwrap().x, tiles.last().unwrap().y)]
+        {
+            tiles.push(tile);
+        }
+        tiles.reverse();
+
+        Some(SeaRoute {
+            tiles,
+            length: cost as f32 / STRAIGHT_COST as f32,
+        })
+    }
+}
+
+/// Precomputed sea routes between all settlements.
+#[derive(Default)]
+pub struct SeaRoutes(HashMap<(String, String), SeaRoute>);
+
+impl SeaRoutes {
+    pub fn get(&self, from: &str, to: &str) -> Option<&SeaRoute> {
+        self.0.get(&(from.to_owned(), to.to_owned()))
+    }
+
+    pub fn insert(&mut self, from: &str, to: &str, route: SeaRoute) {
+        self.0
+            .insert((to.to_owned(), from.to_owned()), route.reversed());
+        self.0.insert((from.to_owned(), to.to_owned()), route);
+    }
+}
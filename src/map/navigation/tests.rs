@@ -0,0 +1,75 @@
+use super::*;
+
+fn grid(rows: &[&str]) -> NavigationGrid {
+    let water = rows
+        .iter()
+        .flat_map(|row| row.chars().map(|c| c == '~'))
+        .collect();
+
+    NavigationGrid::new(rows[0].len() as u32, rows.len() as u32, water)
+}
+
+#[test]
+fn routes_straight_over_open_water() {
+    let grid = grid(&["#~~~~#", "~~~~~~"]);
+
+    let route = grid.route(UVec2::new(0, 0), UVec2::new(5, 0)).unwrap();
+
+    assert_eq!(route.length, 5.);
+    assert_eq!(route.tiles.first(), Some(&UVec2::new(0, 0)));
+    assert_eq!(route.tiles.last(), Some(&UVec2::new(5, 0)));
+}
+
+#[test]
+fn routes_around_land() {
+    let grid = grid(&[
+        "#~#~#", //
+        "~~#~~", //
+        "~~#~~", //
+        "~~~~~",
+    ]);
+
+    let route = grid.route(UVec2::new(0, 0), UVec2::new(4, 0)).unwrap();
+
+    assert!(route.length > 4.);
+    for tile in route.tiles.iter() {
+        assert_ne!(tile.x, 2, "route crosses land at {:?}", tile);
+    }
+}
+
+#[test]
+fn does_not_cut_corners() {
+    let grid = grid(&[
+        "~#", //
+        "#~",
+    ]);
+
+    assert!(grid.route(UVec2::new(0, 0), UVec2::new(1, 1)).is_none());
+}
+
+#[test]
+fn longer_voyages_take_more_seasons() {
+    let grid = grid(&["#~~~~~~~~~~~~~~~~~~~~~~~~~~~~#"]);
+
+    let short = grid.route(UVec2::new(0, 0), UVec2::new(5, 0)).unwrap();
+    let long = grid.route(UVec2::new(0, 0), UVec2::new(29, 0)).unwrap();
+
+    assert_eq!(short.seasons(10.), 1);
+    assert_eq!(long.seasons(10.), 3);
+}
+
+#[test]
+fn stores_routes_both_ways() {
+    let grid = grid(&["#~~#"]);
+    let mut routes = SeaRoutes::default();
+
+    routes.insert(
+        "Olbia",
+        "Istria",
+        grid.route(UVec2::new(0, 0), UVec2::new(3, 0)).unwrap(),
+    );
+
+    let back = routes.get("Istria", "Olbia").unwrap();
+    assert_eq!(back.tiles.first(), Some(&UVec2::new(3, 0)));
+    assert_eq!(routes.get("Olbia", "Phasis").map(|r| r.length), None);
+}
@@ -2,17 +2,33 @@ use crate::types::Player;
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::*;
 
-use super::MapSize;
+use super::{
+    route_overlay::{RouteMarker, RouteOverlay},
+    update_merchant_markers::MerchantMarker,
+    MapSize,
+};
 
 pub fn on_exit(
     mut commands: Commands,
     tilemap_query: Query<Entity, With<TileStorage>>,
     mut texture_atlases: ResMut<Assets<TextureAtlas>>,
     mut player: ResMut<Player>,
+    markers: (
+        Query<Entity, With<MerchantMarker>>,
+        Query<Entity, With<RouteMarker>>,
+    ),
+    mut route_overlay: ResMut<RouteOverlay>,
 ) {
-    for entity in tilemap_query.iter() {
+    let (merchant_markers, route_markers) = markers;
+
+    for entity in tilemap_query
+        .iter()
+        .chain(merchant_markers.iter())
+        .chain(route_markers.iter())
+    {
         commands.entity(entity).despawn_recursive();
     }
+    route_overlay.reset();
 
     if let Some(entity) = player.location_marker {
         commands.entity(entity).despawn_recursive();
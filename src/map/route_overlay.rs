@@ -0,0 +1,128 @@
+use crate::{
+    game_time::GameTime,
+    map::{
+        constants::{TILEMAP_SIZE, Z_ROUTE},
+        navigation::SeaRoutes,
+        MapSize,
+    },
+    player::PlayerTravelEvent,
+    settlement::Settlement,
+    types::Player,
+    ui::SelectedSettlement,
+};
+use bevy::prelude::*;
+
+const ROUTE_COLOR: Color = Color::rgba(1.0, 1.0, 1.0, 0.9);
+const TRAIL_COLOR: Color = Color::rgba(0.1, 0.1, 0.3, 0.3);
+const ROUTE_MARKER_SIZE: f32 = 4.;
+
+/// voyages of the player in the current year
+#[derive(Default)]
+pub struct VoyageTrail {
+    year: i16,
+    routes: Vec<Vec<UVec2>>,
+}
+
+/// what the route markers were last drawn for
+#[derive(Default)]
+pub struct RouteOverlay {
+    drawn: Option<(Option<Entity>, i16, usize)>,
+}
+
+impl RouteOverlay {
+    pub fn reset(&mut self) {
+        self.drawn = None;
+    }
+}
+
+#[derive(Component)]
+pub struct RouteMarker;
+
+pub fn record_voyage_trail(
+    mut events: EventReader<PlayerTravelEvent>,
+    game_time: Res<GameTime>,
+    mut trail: ResMut<VoyageTrail>,
+) {
+    if trail.year != game_time.year {
+        trail.year = game_time.year;
+        trail.routes.clear();
+    }
+
+    for event in events.iter() {
+        trail.routes.push(event.route.clone());
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn update_route_overlay(
+    mut commands: Commands,
+    map_size: Option<Res<MapSize>>,
+    selected_settlement: Option<Res<SelectedSettlement>>,
+    voyage: (
+        Option<Res<Player>>,
+        Option<Res<SeaRoutes>>,
+        Res<VoyageTrail>,
+    ),
+    settlements: Query<&Settlement>,
+    markers: Query<Entity, With<RouteMarker>>,
+    mut overlay: ResMut<RouteOverlay>,
+) {
+    let (player, sea_routes, trail) = voyage;
+    if map_size.is_none() || player.is_none() {
+        return;
+    }
+    let map_size = map_size.unwrap();
+    let player = player.unwrap();
+
+    let selected = selected_settlement
+        .map(|selected| selected.0)
+        .filter(|selected| player.location != Some(*selected));
+    let drawn = Some((selected, trail.year, trail.routes.len()));
+    if overlay.drawn == drawn {
+        return;
+    }
+    overlay.drawn = drawn;
+
+    for entity in markers.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let mut spawn_marker = |tile: &UVec2, color: Color| {
+        commands
+            .spawn_bundle(SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::splat(ROUTE_MARKER_SIZE)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(
+                    tile.x as f32 * TILEMAP_SIZE,
+                    (map_size.height - 1 - tile.y) as f32 * TILEMAP_SIZE,
+                    Z_ROUTE,
+                ),
+                ..default()
+            })
+            .insert(RouteMarker);
+    };
+
+    for route in trail.routes.iter() {
+        for tile in route.iter() {
+            spawn_marker(tile, TRAIL_COLOR);
+        }
+    }
+
+    let planned_route = selected
+        .zip(player.location)
+        .zip(sea_routes.as_ref())
+        .and_then(|((selected, location), sea_routes)| {
+            let from = settlements.get(location).ok()?;
+            let to = settlements.get(selected).ok()?;
+            sea_routes.get(&from.name, &to.name)
+        });
+
+    if let Some(route) = planned_route {
+        for tile in route.tiles.iter() {
+            spawn_marker(tile, ROUTE_COLOR);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{
+    map::{
+        constants::{PLAYER_MARKER, TILEMAP_COLUMNS, TILEMAP_ROWS, TILEMAP_SIZE, Z_MARKER},
+        MapSize,
+    },
+    merchant::MerchantConvoy,
+};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+const MERCHANT_MARKER_COLOR: Color = Color::rgb(0.6, 0.6, 0.6);
+
+/// map marker following the merchant convoy it points to
+#[derive(Component)]
+pub struct MerchantMarker(Entity);
+
+pub fn update_merchant_markers(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    mut texture_atlas_handle: Local<Option<Handle<TextureAtlas>>>,
+    map_size: Option<Res<MapSize>>,
+    convoys: Query<(Entity, &MerchantConvoy)>,
+    mut markers: Query<(Entity, &MerchantMarker, &mut Transform)>,
+) {
+    if map_size.is_none() {
+        return;
+    }
+    let map_size = map_size.unwrap();
+
+    let mut marker_transforms: HashMap<Entity, Mut<Transform>> = HashMap::new();
+    for (entity, marker, transform) in markers.iter_mut() {
+        if convoys.get(marker.0).is_ok() {
+            marker_transforms.insert(marker.0, transform);
+        } else {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    for (entity, convoy) in convoys.iter() {
+        let translation = Vec3::new(
+            convoy.x * TILEMAP_SIZE,
+            (map_size.height as f32 - convoy.y) * TILEMAP_SIZE,
+            Z_MARKER,
+        );
+
+        if let Some(transform) = marker_transforms.get_mut(&entity) {
+            if transform.translation != translation {
+                transform.translation = translation;
+            }
+            continue;
+        }
+
+        let texture_atlas = texture_atlas_handle
+            .get_or_insert_with(|| {
+                let texture_handle: Handle<Image> = asset_server.load("tiles.png");
+
+                texture_atlases.add(TextureAtlas::from_grid(
+                    texture_handle,
+                    Vec2::splat(TILEMAP_SIZE),
+                    TILEMAP_COLUMNS,
+                    TILEMAP_ROWS,
+                ))
+            })
+            .clone();
+
+        commands
+            .spawn_bundle(SpriteSheetBundle {
+                sprite: TextureAtlasSprite {
+                    index: PLAYER_MARKER.0 as usize,
+                    color: MERCHANT_MARKER_COLOR,
+                    ..default()
+                },
+                texture_atlas,
+                transform: Transform::from_translation(translation),
+                ..default()
+            })
+            .insert(MerchantMarker(entity));
+    }
+}
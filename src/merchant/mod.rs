@@ -0,0 +1,100 @@
+use crate::settlement::Settlement;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+mod sail_convoys;
+mod trade;
+
+#[cfg(test)]
+mod tests;
+
+pub use sail_convoys::sail_convoys;
+
+pub const MERCHANT: &str = "Merchant";
+/// units of cargo a single convoy can carry
+const CONVOY_CAPACITY: u32 = 20;
+/// tiles a convoy sails per season
+const CONVOY_SPEED: f32 = 15.;
+/// silver a new convoy takes from its home settlement
+const CONVOY_SILVER: u32 = 200;
+/// convoys do not sail further than this for a single trade
+const MAX_ROUTE_DISTANCE: f32 = 45.;
+/// convoys only buy goods this much cheaper than the average price
+const BUY_MARGIN: f32 = 0.9;
+
+/// A fleet of an NPC merchant, sailing out of the settlement of its merchant
+/// population to sell its goods where they fetch the best price.
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantConvoy {
+    pub home: String,
+    pub x: f32,
+    pub y: f32,
+    /// settlement the convoy is docked at
+    pub location: Option<String>,
+    /// settlement the convoy is sailing to
+    pub destination: Option<String>,
+    /// tiles left to sail on the way to the destination
+    #[serde(default)]
+    pub course: Vec<(u32, u32)>,
+    pub cargo: HashMap<String, u32>,
+    pub silver: u32,
+}
+
+impl MerchantConvoy {
+    pub fn new(home: &mut Settlement) -> Self {
+        let silver = u32::min(home.silver, CONVOY_SILVER);
+        home.silver -= silver;
+
+        Self {
+            home: home.name.clone(),
+            x: home.position.x as f32,
+            y: home.position.y as f32,
+            location: Some(home.name.clone()),
+            destination: None,
+            course: vec![],
+            cargo: HashMap::new(),
+            silver,
+        }
+    }
+
+    pub fn position(&self) -> Vec2 {
+        Vec2::new(self.x, self.y)
+    }
+
+    pub fn cargo_used(&self) -> u32 {
+        self.cargo.values().sum()
+    }
+
+    /// Sails one season along the course towards the target, straight ahead
+    /// where no course is known. Returns whether the target was reached.
+    pub fn sail(&mut self, target: Vec2) -> bool {
+        let mut range = CONVOY_SPEED;
+
+        while let Some((x, y)) = self.course.first().copied() {
+            let tile = Vec2::new(x as f32, y as f32);
+            let step = self.position().distance(tile);
+            if step > range {
+                return false;
+            }
+
+            range -= step;
+            self.x = tile.x;
+            self.y = tile.y;
+            self.course.remove(0);
+        }
+
+        let course = target - self.position();
+        if course.length() > range {
+            let position = self.position() + course.normalize() * range;
+            self.x = position.x;
+            self.y = position.y;
+            return false;
+        }
+
+        self.x = target.x;
+        self.y = target.y;
+        true
+    }
+}
@@ -0,0 +1,173 @@
+use super::{MerchantConvoy, CONVOY_SPEED, MAX_ROUTE_DISTANCE, MERCHANT};
+use crate::{
+    game_rng::GameRng, game_time::GameTimeAdvancedEvent, map::navigation::SeaRoutes,
+    price_calculator::AveragePrices, resources::Resource, settlement::Settlement,
+};
+use bevy::prelude::*;
+use rand::seq::SliceRandom;
+use std::collections::{HashMap, HashSet};
+
+pub fn sail_convoys(
+    mut commands: Commands,
+    mut events: EventReader<GameTimeAdvancedEvent>,
+    mut convoys: Query<(Entity, &mut MerchantConvoy)>,
+    mut settlements: Query<(Entity, &mut Settlement)>,
+    resources: Option<Res<Vec<Resource>>>,
+    average_prices: Res<AveragePrices>,
+    sea_routes: Option<Res<SeaRoutes>>,
+    mut rng: ResMut<GameRng>,
+) {
+    if resources.is_none() {
+        return;
+    }
+    let resources = resources.unwrap();
+
+    let advances = events.iter().count();
+    if advances == 0 {
+        return;
+    }
+
+    let locations: Vec<(Entity, String, Vec2)> = settlements
+        .iter()
+        .map(|(entity, settlement)| {
+            (
+                entity,
+                settlement.name.clone(),
+                Vec2::new(settlement.position.x as f32, settlement.position.y as f32),
+            )
+        })
+        .collect();
+    let find_location = |name: &str| locations.iter().find(|(_, n, _)| n == name);
+
+    // the headless simulation has no map, there convoys sail straight
+    let distance = |from: &str, to: &str| match &sea_routes {
+        Some(sea_routes) => sea_routes.get(from, to).map(|route| route.length),
+        None => Some(find_location(from)?.2.distance(find_location(to)?.2)),
+    };
+
+    // every merchant population runs one convoy
+    let mut convoy_counts: HashMap<String, usize> = HashMap::new();
+    for (_, convoy) in convoys.iter() {
+        *convoy_counts.entry(convoy.home.clone()).or_default() += 1;
+    }
+
+    let mut retiring: HashMap<String, usize> = HashMap::new();
+    for (_, mut settlement) in settlements.iter_mut() {
+        let merchants = settlement
+            .populations
+            .iter()
+            .filter(|p| *p == MERCHANT)
+            .count();
+        let convoy_count = *convoy_counts.get(&settlement.name).unwrap_or(&0);
+
+        for _ in convoy_count..merchants {
+            commands
+                .spawn()
+                .insert(MerchantConvoy::new(&mut settlement));
+        }
+        if convoy_count > merchants {
+            retiring.insert(settlement.name.clone(), convoy_count - merchants);
+        }
+    }
+
+    // spawned convoys only show up next frame and retired ones are gone
+    // only then, so this runs once per frame however many seasons passed
+    let mut retired: HashSet<Entity> = HashSet::new();
+    for _ in 0..advances {
+        for (entity, mut convoy) in convoys.iter_mut() {
+            if retired.contains(&entity) {
+                continue;
+            }
+
+            if let Some(destination) = convoy.destination.clone() {
+                let (settlement_entity, _, target) = match find_location(&destination) {
+                    Some(location) => location,
+                    None => {
+                        log::warn!("convoy sails to unknown settlement {}", destination);
+                        continue;
+                    }
+                };
+                if !convoy.sail(*target) {
+                    continue;
+                }
+
+                convoy.location = Some(destination);
+                convoy.destination = None;
+
+                let (_, mut settlement) = settlements.get_mut(*settlement_entity).unwrap();
+                convoy.sell_cargo(&mut settlement, &resources);
+            }
+
+            let location = match convoy.location.clone() {
+                Some(location) => location,
+                None => continue,
+            };
+            let (settlement_entity, _, _) = match find_location(&location) {
+                Some(location) => location,
+                None => {
+                    log::warn!("convoy docked at unknown settlement {}", location);
+                    continue;
+                }
+            };
+
+            // retired convoys hand their earnings back to their home
+            if location == convoy.home {
+                if let Some(count) = retiring.get_mut(&location).filter(|count| **count > 0) {
+                    *count -= 1;
+                    let (_, mut settlement) = settlements.get_mut(*settlement_entity).unwrap();
+                    settlement.silver += convoy.silver;
+                    convoy.silver = 0;
+                    for (resource_name, amount) in convoy.cargo.drain() {
+                        *settlement.resources.entry(resource_name).or_default() += amount;
+                    }
+                    commands.entity(entity).despawn();
+                    retired.insert(entity);
+                    continue;
+                }
+            }
+
+            {
+                let (_, mut settlement) = settlements.get_mut(*settlement_entity).unwrap();
+                convoy.buy_cargo(&mut settlement, &resources, &average_prices);
+            }
+
+            let reachable: Vec<(Entity, &String, f32)> = locations
+                .iter()
+                .filter(|(_, name, _)| *name != location)
+                .filter_map(|(entity, name, _)| Some((*entity, name, distance(&location, name)?)))
+                .filter(|(_, _, distance)| *distance <= MAX_ROUTE_DISTANCE)
+                .collect();
+
+            // sail where the cargo is worth the most per season at sea,
+            // or look for bargains elsewhere when the hold is empty
+            let destination = if convoy.cargo_used() > 0 {
+                reachable
+                    .iter()
+                    .map(|(entity, name, distance)| {
+                        let (_, settlement) = settlements.get(*entity).unwrap();
+                        let seasons = (distance / CONVOY_SPEED).ceil();
+                        let value = convoy.cargo_value(settlement, &resources) as f32;
+                        (name, value / f32::max(seasons, 1.))
+                    })
+                    .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+                    .map(|(name, _)| name.to_string())
+            } else if location != convoy.home {
+                Some(convoy.home.clone())
+            } else {
+                reachable
+                    .choose(&mut *rng)
+                    .map(|(_, name, _)| name.to_string())
+            };
+
+            if let Some(destination) = destination {
+                convoy.course = sea_routes
+                    .as_ref()
+                    .and_then(|sea_routes| sea_routes.get(&location, &destination))
+                    .map(|route| route.tiles.iter().map(|tile| (tile.x, tile.y)).collect())
+                    .unwrap_or_default();
+                convoy.location = None;
+                convoy.destination = Some(destination);
+            }
+        }
+    }
+}
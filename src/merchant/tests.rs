@@ -0,0 +1,63 @@
+use super::*;
+use crate::{price_calculator::AveragePrices, resources::Resource};
+
+fn setup() -> (Settlement, Vec<Resource>, AveragePrices) {
+    let mut settlement =
+        Settlement::test_city("Olbia", &["Merchant"], &[("Grain", 100), ("Wine", 1)]);
+    settlement.silver = 1000;
+
+    let resources: Vec<Resource> = serde_yaml::from_str(
+        "
+- name: Grain
+  base_price: 10
+  demand:
+    Population: 10
+  max:
+    Population: 100
+- name: Wine
+  base_price: 30
+  demand:
+    Population: 10
+  max:
+    Population: 100
+",
+    )
+    .unwrap();
+
+    let mut average_prices = AveragePrices::default();
+    average_prices.prices.insert("Grain".to_owned(), 12.);
+    average_prices.prices.insert("Wine".to_owned(), 30.);
+
+    (settlement, resources, average_prices)
+}
+
+#[test]
+fn buys_goods_cheaper_than_average() {
+    let (mut settlement, resources, average_prices) = setup();
+    let mut convoy = MerchantConvoy::new(&mut settlement);
+
+    convoy.buy_cargo(&mut settlement, &resources, &average_prices);
+
+    assert_eq!(convoy.cargo.get("Grain"), Some(&CONVOY_CAPACITY));
+    assert_eq!(convoy.cargo.get("Wine"), None);
+    assert_eq!(
+        settlement.resources.get("Grain"),
+        Some(&(100 - CONVOY_CAPACITY))
+    );
+    assert_eq!(settlement.silver + convoy.silver, 1000);
+}
+
+#[test]
+fn sells_only_what_settlement_can_pay() {
+    let (mut settlement, resources, _) = setup();
+    let mut convoy = MerchantConvoy::new(&mut settlement);
+    convoy.cargo.insert("Wine".to_owned(), 10);
+    settlement.silver = 100;
+
+    convoy.sell_cargo(&mut settlement, &resources);
+
+    let sold = 10 - convoy.cargo.get("Wine").unwrap();
+    assert!(sold > 0);
+    assert_eq!(settlement.resources.get("Wine"), Some(&(1 + sold)));
+    assert_eq!(settlement.silver + convoy.silver, 100 + CONVOY_SILVER);
+}
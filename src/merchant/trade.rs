@@ -0,0 +1,82 @@
+use super::{MerchantConvoy, BUY_MARGIN, CONVOY_CAPACITY};
+use crate::{price_calculator::AveragePrices, resources::Resource, settlement::Settlement};
+
+impl MerchantConvoy {
+    /// sells as much cargo as the settlement can pay for
+    pub fn sell_cargo(&mut self, settlement: &mut Settlement, resources: &[Resource]) {
+        let mut cargo: Vec<(String, u32)> = self.cargo.drain().collect();
+        cargo.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+        for (resource_name, mut amount) in cargo.into_iter() {
+            if let Some(resource) = resources.iter().find(|r| r.name == resource_name) {
+                while amount > 0 {
+                    let price = settlement.prices(resource).sell_price();
+                    if settlement.silver < price {
+                        break;
+                    }
+
+                    settlement.silver -= price;
+                    self.silver += price;
+                    *settlement
+                        .resources
+                        .entry(resource_name.clone())
+                        .or_default() += 1;
+                    amount -= 1;
+                }
+            }
+
+            if amount > 0 {
+                self.cargo.insert(resource_name, amount);
+            }
+        }
+    }
+
+    /// fills the cargo hold with goods that are cheap compared to the
+    /// average price, best bargain first
+    pub fn buy_cargo(
+        &mut self,
+        settlement: &mut Settlement,
+        resources: &[Resource],
+        average_prices: &AveragePrices,
+    ) {
+        while self.cargo_used() < CONVOY_CAPACITY {
+            let bargain = resources
+                .iter()
+                .filter_map(|resource| {
+                    if *settlement.resources.get(&resource.name).unwrap_or(&0) == 0 {
+                        return None;
+                    }
+
+                    let average_price = average_prices.prices.get(&resource.name)?;
+                    let price = settlement.prices(resource).buy_price();
+                    if price > self.silver || price as f32 >= average_price * BUY_MARGIN {
+                        return None;
+                    }
+
+                    Some((resource, price, average_price / u32::max(price, 1) as f32))
+                })
+                .max_by(|a, b| a.2.partial_cmp(&b.2).unwrap());
+
+            match bargain {
+                Some((resource, price, _)) => {
+                    self.silver -= price;
+                    settlement.silver += price;
+                    *settlement.resources.get_mut(&resource.name).unwrap() -= 1;
+                    *self.cargo.entry(resource.name.clone()).or_default() += 1;
+                }
+                None => break,
+            }
+        }
+    }
+
+    /// what the current cargo would fetch in the given settlement
+    pub fn cargo_value(&self, settlement: &Settlement, resources: &[Resource]) -> u32 {
+        self.cargo
+            .iter()
+            .filter_map(|(resource_name, amount)| {
+                let resource = resources.iter().find(|r| r.name == *resource_name)?;
+                Some(settlement.prices(resource).sell_price() * amount)
+            })
+            .sum()
+    }
+}
@@ -1,21 +1,36 @@
-use crate::{game_time::GameTimeAdvanceEvent, player::PlayerTravelEvent, types::Player};
+use crate::{
+    game_time::{GameTime, GameTimeAdvanceEvent},
+    player::{winter_share, PlayerTravelEvent},
+    types::Player,
+    Settings,
+};
 use bevy::prelude::*;
 
 pub fn handle_travel(
     mut events: EventReader<PlayerTravelEvent>,
     mut player: Option<ResMut<Player>>,
     mut advance_time_events: EventWriter<GameTimeAdvanceEvent>,
+    game_time: Res<GameTime>,
+    settings: Option<Res<Settings>>,
 ) {
     for event in events.iter() {
         if let Some(player) = &mut player {
             if player.position != event.position {
                 log::info!(
-                    "Player traveled to {}:{}",
+                    "Player traveled to {}:{} in {} seasons",
                     event.position.x,
-                    event.position.y
+                    event.position.y,
+                    event.seasons
                 );
                 player.update_position(event.position, Some(event.entity));
-                advance_time_events.send(GameTimeAdvanceEvent);
+                player.start_insured_voyage(&game_time);
+                if let Some(settings) = settings.as_ref() {
+                    let winter = winter_share(&game_time, event.seasons);
+                    player.wear_convoy(event.length, winter, &settings.wear);
+                }
+                for _ in 0..event.seasons {
+                    advance_time_events.send(GameTimeAdvanceEvent);
+                }
             }
         }
     }
@@ -0,0 +1,110 @@
+use crate::{
+    game_time::GameTime,
+    price_calculator::AveragePrices,
+    types::{Insurance, InsuranceTerm, Player},
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct InsuranceSettings {
+    /// share of the cargo value paid to cover one voyage
+    pub voyage_premium: f32,
+    /// share of the cargo value paid to cover all voyages starting in a season
+    pub season_premium: f32,
+    /// multiplier for the premium in winter
+    pub winter: f32,
+    /// share of the value of lost cargo paid out
+    pub payout: f32,
+}
+
+/// value of the cargo at the average prices of all settlements
+pub fn cargo_value(cargo: &HashMap<String, u32>, prices: &AveragePrices) -> u32 {
+    cargo
+        .iter()
+        .map(|(resource, amount)| *amount as f32 * prices.prices.get(resource).unwrap_or(&0.))
+        .sum::<f32>()
+        .round() as u32
+}
+
+impl Player {
+    /// Premium for covering cargo of the given value, damaged ships and
+    /// winter voyages are a higher risk.
+    pub fn insurance_premium(
+        &self,
+        term: InsuranceTerm,
+        value: u32,
+        time: &GameTime,
+        settings: &InsuranceSettings,
+    ) -> u32 {
+        let rate = match term {
+            InsuranceTerm::Voyage => settings.voyage_premium,
+            InsuranceTerm::Season { .. } => settings.season_premium,
+        };
+        let health = if self.convoy.is_empty() {
+            1.
+        } else {
+            self.convoy.iter().map(|ship| ship.health()).sum::<f32>() / self.convoy.len() as f32
+        };
+        let season = if time.is_winter_season() {
+            settings.winter
+        } else {
+            1.
+        };
+
+        (value as f32 * rate * (2. - health) * season).ceil() as u32
+    }
+
+    pub fn insure(&mut self, term: InsuranceTerm, value: u32, premium: u32) {
+        self.silver -= u32::min(premium, self.silver);
+        self.insurance = Some(Insurance {
+            term,
+            value,
+            covering: false,
+        });
+    }
+
+    /// A voyage insurance covers the next voyage only, a season insurance
+    /// the voyages starting in its season.
+    pub fn start_insured_voyage(&mut self, time: &GameTime) {
+        if let Some(insurance) = self.insurance.as_mut() {
+            let covering = match insurance.term {
+                InsuranceTerm::Voyage => !insurance.covering,
+                InsuranceTerm::Season { year, season } => {
+                    year == time.year && season == time.season
+                }
+            };
+
+            if covering {
+                insurance.covering = true;
+            } else {
+                self.insurance = None;
+            }
+        }
+    }
+
+    /// pays out part of the value of cargo lost at sea, returns the silver paid
+    pub fn claim_insurance(
+        &mut self,
+        lost: &HashMap<String, u32>,
+        prices: &AveragePrices,
+        settings: &InsuranceSettings,
+    ) -> u32 {
+        let insurance = match self.insurance.as_mut() {
+            Some(insurance) if insurance.covering => insurance,
+            _ => return 0,
+        };
+
+        let payout = u32::min(
+            (cargo_value(lost, prices) as f32 * settings.payout).round() as u32,
+            insurance.value,
+        );
+        insurance.value -= payout;
+        self.silver += payout;
+        payout
+    }
+}
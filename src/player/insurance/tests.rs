@@ -0,0 +1,88 @@
+use super::*;
+use crate::types::{Ship, ShipClass};
+use bevy::prelude::*;
+
+fn settings() -> InsuranceSettings {
+    InsuranceSettings {
+        voyage_premium: 0.05,
+        season_premium: 0.1,
+        winter: 2.,
+        payout: 0.8,
+    }
+}
+
+fn prices() -> AveragePrices {
+    let mut prices = AveragePrices::default();
+    prices.prices.insert("Wine".to_owned(), 30.);
+    prices.prices.insert("Grain".to_owned(), 10.);
+    prices
+}
+
+fn player() -> Player {
+    let ship = Ship::new(&ShipClass::test_class("Small Ship", 20));
+
+    Player {
+        silver: 1000,
+        convoy: vec![ship],
+        ..default()
+    }
+}
+
+#[test]
+fn premium_depends_on_health_and_season() {
+    let mut player = player();
+    let summer = GameTime { year: 2, season: 3 };
+    let winter = GameTime { year: 2, season: 5 };
+
+    assert_eq!(
+        player.insurance_premium(InsuranceTerm::Voyage, 1000, &summer, &settings()),
+        50
+    );
+    assert_eq!(
+        player.insurance_premium(InsuranceTerm::Voyage, 1000, &winter, &settings()),
+        100
+    );
+
+    player.convoy[0].damage = 10;
+    assert_eq!(
+        player.insurance_premium(InsuranceTerm::Voyage, 1000, &summer, &settings()),
+        75
+    );
+}
+
+#[test]
+fn pays_out_for_covered_voyage() {
+    let mut player = player();
+    let time = GameTime { year: 2, season: 3 };
+    let mut lost = HashMap::new();
+    lost.insert("Wine".to_owned(), 10);
+    lost.insert("Grain".to_owned(), 10);
+
+    player.insure(InsuranceTerm::Voyage, 300, 15);
+    assert_eq!(player.silver, 985);
+
+    // nothing is paid before the voyage started
+    assert_eq!(player.claim_insurance(&lost, &prices(), &settings()), 0);
+
+    player.start_insured_voyage(&time);
+    assert_eq!(player.claim_insurance(&lost, &prices(), &settings()), 300);
+    assert_eq!(player.silver, 1285);
+
+    // the next voyage is not covered anymore
+    player.start_insured_voyage(&time);
+    assert!(player.insurance.is_none());
+}
+
+#[test]
+fn season_insurance_covers_voyages_of_season() {
+    let mut player = player();
+    let time = GameTime { year: 2, season: 3 };
+    player.insure(InsuranceTerm::Season { year: 2, season: 3 }, 300, 30);
+
+    player.start_insured_voyage(&time);
+    player.start_insured_voyage(&time);
+    assert!(player.insurance.as_ref().unwrap().covering);
+
+    player.start_insured_voyage(&GameTime { year: 2, season: 4 });
+    assert!(player.insurance.is_none());
+}
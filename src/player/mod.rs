@@ -1,19 +1,46 @@
+use crate::{game_rng::GameRngLabel, settlement::SettlementLabel};
 use bevy::prelude::*;
 
 mod handle_travel;
+mod insurance;
+mod pay_upkeep;
 mod shipwreck_check;
 mod shipwreck_remove;
+mod voyage_wear;
+
+pub use insurance::{cargo_value, InsuranceSettings};
+pub use pay_upkeep::UpkeepSettings;
+pub use voyage_wear::{winter_share, WearSettings};
 
 pub struct PlayerTravelEvent {
     position: Vec2,
     entity: Entity,
+    /// how long the voyage takes
+    pub seasons: u32,
+    /// tiles sailed on the voyage
+    pub route: Vec<UVec2>,
+    /// length of the voyage in tiles
+    pub length: f32,
 }
 
 impl PlayerTravelEvent {
-    pub fn new(entity: Entity, x: u32, y: u32) -> Self {
+    pub fn new(
+        entity: Entity,
+        x: u32,
+        y: u32,
+        seasons: u32,
+        route: Vec<UVec2>,
+        length: f32,
+    ) -> Self {
         let position = Vec2::new(x as f32, y as f32);
 
-        Self { position, entity }
+        Self {
+            position,
+            entity,
+            seasons,
+            route,
+            length,
+        }
     }
 }
 
@@ -27,8 +54,18 @@ impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<PlayerTravelEvent>()
             .add_event::<PlayerShipwreckEvent>()
-            .add_system(shipwreck_check::shipwreck_check)
-            .add_system(shipwreck_remove::shipwreck_remove)
-            .add_system(handle_travel::handle_travel);
+            .add_system(shipwreck_check::shipwreck_check.before(GameRngLabel::Shipwreck))
+            .add_system(
+                shipwreck_remove::shipwreck_remove
+                    .label(GameRngLabel::Shipwreck)
+                    .after(GameRngLabel::EventEffect),
+            )
+            .add_system(handle_travel::handle_travel)
+            .add_system(voyage_wear::age_ships)
+            .add_system(
+                pay_upkeep::pay_upkeep
+                    .after(SettlementLabel::CapResources)
+                    .before(GameRngLabel::Trade),
+            );
     }
 }
@@ -0,0 +1,132 @@
+use crate::{
+    game_time::GameTimeAdvancedEvent,
+    resources::Resource,
+    settlement::Settlement,
+    types::{Player, Upkeep},
+    Settings,
+};
+use bevy::prelude::*;
+use serde::Deserialize;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct UpkeepSettings {
+    /// silver each sailor earns per season
+    pub wage: u32,
+    /// silver paid for each sailor hired in port
+    pub hiring_fee: u32,
+    /// sailors fed by one unit of provisions per season
+    pub sailors_per_provision: u32,
+    /// resources the crew eats, in order of preference
+    pub provisions: Vec<String>,
+    /// share of unpaid or hungry sailors leaving each season
+    pub desertion: f32,
+}
+
+pub fn pay_upkeep(
+    mut events: EventReader<GameTimeAdvancedEvent>,
+    player: Option<ResMut<Player>>,
+    mut settlements: Query<&mut Settlement>,
+    resources: Option<Res<Vec<Resource>>>,
+    settings: Option<Res<Settings>>,
+) {
+    if player.is_none() || resources.is_none() || settings.is_none() {
+        return;
+    }
+    let mut player = player.unwrap();
+    let resources = resources.unwrap();
+    let settings = settings.unwrap();
+
+    // no wages are due while the world is warming up
+    let seasons = events
+        .iter()
+        .filter(|event| event.time.is_initialized())
+        .count();
+
+    for season in 1..=seasons {
+        // the location is set to the destination on departure already, the
+        // convoy only reaches its port in the last season of a voyage
+        let mut port = if season == seasons {
+            player
+                .location
+                .and_then(|location| settlements.get_mut(location).ok())
+        } else {
+            None
+        };
+        player.pay_upkeep(port.as_deref_mut(), &resources, &settings.upkeep);
+    }
+}
+
+impl Player {
+    /// Pays the crew and feeds it from the cargo or the market of the port.
+    /// Sailors left without silver or food desert, missing ones are hired
+    /// in port again.
+    pub fn pay_upkeep(
+        &mut self,
+        mut port: Option<&mut Settlement>,
+        resources: &[Resource],
+        settings: &UpkeepSettings,
+    ) {
+        let mut upkeep = Upkeep::default();
+        let crew = self.crew();
+
+        let wages = crew * settings.wage;
+        upkeep.wages = u32::min(wages, self.silver);
+        self.silver -= upkeep.wages;
+        let unpaid = (wages - upkeep.wages) as f32 / u32::max(wages, 1) as f32;
+
+        let needed =
+            (crew as f32 / u32::max(settings.sailors_per_provision, 1) as f32).ceil() as u32;
+        for provision in settings.provisions.iter() {
+            upkeep.provisions += self.remove_resource(provision, needed - upkeep.provisions);
+        }
+
+        if let Some(port) = port.as_mut() {
+            for resource in settings
+                .provisions
+                .iter()
+                .filter_map(|provision| resources.iter().find(|r| r.name == *provision))
+            {
+                while upkeep.provisions < needed {
+                    let price = port.prices(resource).buy_price();
+                    let stock = port.resources.entry(resource.name.clone()).or_default();
+                    if *stock == 0 || self.silver < price {
+                        break;
+                    }
+
+                    *stock -= 1;
+                    self.silver -= price;
+                    port.silver += price;
+                    upkeep.provisions += 1;
+                    upkeep.provisions_bought += price;
+                }
+            }
+        }
+        let hungry = (needed - upkeep.provisions) as f32 / u32::max(needed, 1) as f32;
+
+        let desertion = f32::max(unpaid, hungry) * settings.desertion;
+        if desertion > 0. {
+            for ship in self.convoy.iter_mut() {
+                let deserted = u32::min((ship.crew as f32 * desertion).ceil() as u32, ship.crew);
+                ship.crew -= deserted;
+                upkeep.deserted += deserted;
+            }
+        } else if port.is_some() {
+            for ship in self.convoy.iter_mut() {
+                let missing = ship.class.crew.saturating_sub(ship.crew);
+                let hired = u32::min(missing, self.silver / u32::max(settings.hiring_fee, 1));
+                ship.crew += hired;
+                self.silver -= hired * settings.hiring_fee;
+                upkeep.hired += hired;
+            }
+        }
+
+        if upkeep.deserted > 0 {
+            log::info!("{} sailors deserted the convoy", upkeep.deserted);
+        }
+        self.upkeep = upkeep;
+    }
+}
@@ -0,0 +1,62 @@
+use super::*;
+use crate::types::{Ship, ShipClass};
+
+fn settings() -> UpkeepSettings {
+    UpkeepSettings {
+        wage: 2,
+        hiring_fee: 5,
+        sailors_per_provision: 4,
+        provisions: vec!["Fish".to_owned(), "Grain".to_owned()],
+        desertion: 0.5,
+    }
+}
+
+fn player(silver: u32) -> Player {
+    let ship_class = ShipClass {
+        crew: 8,
+        ..ShipClass::test_class("Small Ship", 20)
+    };
+
+    let mut player = Player {
+        silver,
+        convoy: vec![Ship::new(&ship_class)],
+        ..default()
+    };
+    player.resources.insert("Grain".to_owned(), 5);
+    player
+}
+
+#[test]
+fn pays_wages_and_eats_from_cargo() {
+    let mut player = player(100);
+
+    player.pay_upkeep(None, &[], &settings());
+
+    assert_eq!(player.silver, 84);
+    assert_eq!(player.resources.get("Grain"), Some(&3));
+    assert_eq!(player.upkeep.provisions, 2);
+    assert_eq!(player.crew(), 8);
+}
+
+#[test]
+fn unpaid_sailors_desert_and_slow_the_convoy() {
+    let mut player = player(0);
+    let speed = player.travel_speed();
+
+    player.pay_upkeep(None, &[], &settings());
+
+    assert_eq!(player.upkeep.deserted, 4);
+    assert_eq!(player.crew(), 4);
+    assert!(player.travel_speed() < speed);
+}
+
+#[test]
+fn hungry_sailors_desert() {
+    let mut player = player(100);
+    player.resources.clear();
+
+    player.pay_upkeep(None, &[], &settings());
+
+    assert_eq!(player.upkeep.provisions, 0);
+    assert_eq!(player.crew(), 4);
+}
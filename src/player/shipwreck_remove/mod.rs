@@ -1,6 +1,8 @@
-use crate::{player::PlayerShipwreckEvent, types::Player};
+use crate::{
+    player::PlayerShipwreckEvent, price_calculator::AveragePrices, types::Player, Settings,
+};
 use bevy::prelude::*;
-use rand::{seq::SliceRandom, thread_rng};
+use std::collections::HashMap;
 
 #[cfg(test)]
 mod tests;
@@ -8,27 +10,44 @@ mod tests;
 pub fn shipwreck_remove(
     player: Option<ResMut<Player>>,
     mut shipwreck_events: EventReader<PlayerShipwreckEvent>,
+    insurance: (Option<Res<AveragePrices>>, Option<Res<Settings>>),
 ) {
     if shipwreck_events.is_empty() {
         return;
     }
 
     if let Some(mut player) = player {
-        let remove_index: Vec<usize> = shipwreck_events
+        let mut remove_index: Vec<usize> = shipwreck_events
             .iter()
             .map(|event| event.ship_index)
             .collect();
+        // a ship wrecks only once, however often it was reported
+        remove_index.sort_unstable();
+        remove_index.dedup();
 
-        let lost_capacity = remove_index
+        let mut lost_cargo: HashMap<String, u32> = HashMap::new();
+        for ship in remove_index
             .iter()
-            .fold(0, |acc, index| acc + player.convoy[*index].resource_space());
+            .filter_map(|index| player.convoy.get(*index))
+        {
+            for (resource, amount) in ship.cargo.iter() {
+                *lost_cargo.entry(resource.clone()).or_default() += amount;
+            }
+        }
 
         log::info!(
-            "{} ship(s) shipwrecked ({} capacity)",
+            "{} ship(s) shipwrecked ({} cargo lost)",
             remove_index.len(),
-            lost_capacity
+            lost_cargo.values().sum::<u32>()
         );
 
+        if let (Some(prices), Some(settings)) = insurance {
+            let payout = player.claim_insurance(&lost_cargo, &prices, &settings.insurance);
+            if payout > 0 {
+                log::info!("insurance paid {} for the lost cargo", payout);
+            }
+        }
+
         player.convoy = player
             .convoy
             .clone()
@@ -42,35 +61,5 @@ pub fn shipwreck_remove(
                 }
             })
             .collect();
-
-        let mut random = thread_rng();
-        let mut options: Vec<String> = player
-            .resources
-            .iter()
-            .filter_map(|(res, amount)| {
-                if *amount > 0 {
-                    Some(res.to_owned())
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        let resource_total = player.resource_space_used();
-        let lost_resource_count = (resource_total as f32
-            * (lost_capacity as f32 / (lost_capacity + player.resource_space_total()) as f32))
-            .ceil() as u32;
-
-        for _ in 0..lost_resource_count {
-            options.shuffle(&mut random);
-
-            if let Some(item) = options.first() {
-                let res = player.resources.entry(item.clone()).or_default();
-                *res -= 1;
-                if *res == 0 {
-                    options.remove(0);
-                }
-            }
-        }
     }
 }
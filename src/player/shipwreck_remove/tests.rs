@@ -1,18 +1,20 @@
 use super::*;
-use crate::types::{Player, Ship, ShipSize};
-use std::collections::HashMap;
+use crate::types::{Player, Ship, ShipClass};
 
 fn setup() -> (World, Box<dyn Stage>) {
     let mut world = World::default();
-    let mut resources = HashMap::new();
-    resources.insert("some".to_owned(), 20);
-    resources.insert("thing".to_owned(), 20);
 
-    let player = Player {
-        resources,
-        convoy: vec![Ship::new(ShipSize::Small), Ship::new(ShipSize::Medium)],
+    let mut small = Ship::new(&ShipClass::test_class("Small Ship", 20));
+    small.cargo.insert("some".to_owned(), 15);
+    small.cargo.insert("thing".to_owned(), 5);
+    let mut medium = Ship::new(&ShipClass::test_class("Medium Ship", 50));
+    medium.cargo.insert("thing".to_owned(), 12);
+
+    let mut player = Player {
+        convoy: vec![small, medium],
         ..default()
     };
+    player.resources.insert("some".to_owned(), 3);
     world.insert_resource(Events::<PlayerShipwreckEvent>::default());
     world.insert_resource(player);
 
@@ -38,17 +40,11 @@ fn removes_ship() {
     let player = world.get_resource::<Player>().unwrap();
     assert_eq!(player.convoy.len(), 1);
     let ship = player.convoy.first().unwrap();
-    assert_eq!(ship.size, ShipSize::Medium);
-
-    // removes resources proportionally
-    // we lost a small ship and have a medium,
-    // we lost 20/75 capacity, so ~10.6 -> 11 resources
-    let total = player
-        .resources
-        .iter()
-        .fold(0, |res, (_, amount)| res + amount);
+    assert_eq!(ship.class.name, "Medium Ship");
 
-    assert_eq!(total, 29);
+    // exactly the cargo of the small ship is lost
+    assert_eq!(player.resource_count("some"), 3);
+    assert_eq!(player.resource_count("thing"), 12);
 }
 
 #[test]
@@ -68,12 +64,26 @@ fn removes_multiple_ships() {
     let player = world.get_resource::<Player>().unwrap();
     assert_eq!(player.convoy.len(), 0);
 
-    // removes resources proportionally
-    // we lost 70/75 capacity, so ~37.3 -> 38 resources
-    let total = player
-        .resources
-        .iter()
-        .fold(0, |res, (_, amount)| res + amount);
+    // the base hold survives
+    assert_eq!(player.resource_space_used(), 3);
+}
+
+#[test]
+fn removes_ship_reported_twice_once() {
+    let (mut world, mut stage) = setup();
+
+    {
+        let mut events = world
+            .get_resource_mut::<Events<PlayerShipwreckEvent>>()
+            .unwrap();
+        events.send(PlayerShipwreckEvent { ship_index: 0 });
+        events.send(PlayerShipwreckEvent { ship_index: 0 });
+    }
+
+    stage.run(&mut world);
 
-    assert_eq!(total, 2);
+    let player = world.get_resource::<Player>().unwrap();
+    assert_eq!(player.convoy.len(), 1);
+    assert_eq!(player.resource_count("some"), 3);
+    assert_eq!(player.resource_count("thing"), 12);
 }
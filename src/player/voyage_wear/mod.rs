@@ -0,0 +1,86 @@
+use crate::{
+    game_time::{GameTime, GameTimeAdvancedEvent},
+    types::{Player, Ship},
+};
+use bevy::prelude::*;
+use serde::Deserialize;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct WearSettings {
+    /// damage a ship takes for each tile sailed
+    pub per_tile: f32,
+    /// multiplier for the wear of seasons sailed in winter
+    pub winter: f32,
+    /// additional share of wear for each season a ship is in service
+    pub aging: f32,
+}
+
+impl WearSettings {
+    /// damage the ship takes on a voyage of `length` tiles with the share
+    /// `winter` of it sailed in winter
+    pub fn wear(&self, ship: &Ship, length: f32, winter: f32) -> u32 {
+        let season = 1. + (self.winter - 1.) * winter;
+        let age = 1. + self.aging * ship.age as f32;
+
+        (length * self.per_tile * season * age).round() as u32
+    }
+}
+
+/// share of the seasons of a voyage starting at `time` that fall into winter
+pub fn winter_share(time: &GameTime, seasons: u32) -> f32 {
+    let mut time = time.clone();
+    let mut winter = 0;
+    for _ in 0..seasons {
+        if time.is_winter_season() {
+            winter += 1;
+        }
+        time.advance();
+    }
+
+    winter as f32 / u32::max(seasons, 1) as f32
+}
+
+impl Player {
+    /// damage the ships at the given indices take on a voyage
+    pub fn voyage_wear(
+        &self,
+        sailing: &[usize],
+        length: f32,
+        winter: f32,
+        settings: &WearSettings,
+    ) -> u32 {
+        sailing
+            .iter()
+            .filter_map(|index| self.convoy.get(*index))
+            .map(|ship| settings.wear(ship, length, winter))
+            .sum()
+    }
+
+    /// Wears the ships down on a voyage. Wear alone never sinks a ship,
+    /// but leaves it with less hull to weather a storm.
+    pub fn wear_convoy(&mut self, length: f32, winter: f32, settings: &WearSettings) {
+        for ship in self.convoy.iter_mut() {
+            let wear = settings.wear(ship, length, winter);
+            let max_damage = ship.max_health().saturating_sub(1);
+            ship.damage = u32::max(ship.damage, u32::min(ship.damage + wear, max_damage));
+        }
+    }
+}
+
+pub fn age_ships(mut events: EventReader<GameTimeAdvancedEvent>, player: Option<ResMut<Player>>) {
+    if let Some(mut player) = player {
+        for event in events.iter() {
+            if !event.time.is_initialized() {
+                continue;
+            }
+
+            for ship in player.convoy.iter_mut() {
+                ship.age += 1;
+            }
+        }
+    }
+}
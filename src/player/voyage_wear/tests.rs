@@ -0,0 +1,50 @@
+use super::*;
+use crate::types::ShipClass;
+
+fn settings() -> WearSettings {
+    WearSettings {
+        per_tile: 0.1,
+        winter: 2.,
+        aging: 0.05,
+    }
+}
+
+fn ship() -> Ship {
+    Ship::new(&ShipClass::test_class("Small Ship", 20))
+}
+
+#[test]
+fn wear_grows_with_distance_winter_and_age() {
+    let settings = settings();
+    let mut ship = ship();
+
+    assert_eq!(settings.wear(&ship, 20., 0.), 2);
+    assert_eq!(settings.wear(&ship, 40., 0.), 4);
+    assert_eq!(settings.wear(&ship, 20., 1.), 4);
+    assert_eq!(settings.wear(&ship, 20., 0.5), 3);
+
+    ship.age = 20;
+    assert_eq!(settings.wear(&ship, 20., 0.), 4);
+}
+
+#[test]
+fn wear_does_not_sink_ships() {
+    let mut player = Player {
+        convoy: vec![ship()],
+        ..default()
+    };
+
+    player.wear_convoy(500., 1., &settings());
+
+    assert_eq!(player.convoy[0].damage, 19);
+    assert!(player.convoy[0].health() > 0.);
+}
+
+#[test]
+fn counts_winter_seasons_of_voyage() {
+    let time = GameTime { year: 2, season: 4 };
+
+    assert_eq!(winter_share(&time, 1), 0.);
+    assert_eq!(winter_share(&time, 2), 0.5);
+    assert_eq!(winter_share(&time, 3), 2. / 3.);
+}
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use crate::{
+    game_time::{GameTime, GameTimeAdvancedEvent},
+    resources::Resource,
+    settlement::{ModifierKind, Settlement},
+    types::SeasonalAmount,
+    COIN_NAME,
+};
+use bevy::prelude::*;
+use serde::{Deserialize, Deserializer};
+
+#[cfg(test)]
+mod tests;
+
+pub fn population_production(
+    mut settlements: Query<&mut Settlement>,
+    mut events: EventReader<GameTimeAdvancedEvent>,
+    populations: Option<Res<Vec<Population>>>,
+) {
+    if let Some(populations) = populations {
+        for event in events.iter() {
+            for mut settlement in settlements.iter_mut() {
+                settlement.production_tick(&event.time, &populations);
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Component, Debug, Hash, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Population {
+    pub name: String,
+    pub production: Vec<Production>,
+}
+
+#[derive(Deserialize, Component, Debug, Hash, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Production {
+    pub resource: String,
+    pub amount: SeasonalAmount<u32>,
+    #[serde(default)]
+    pub inputs: Vec<ProductionInput>,
+}
+
+/// `amount` of `resource` is used up for every `per` units produced
+#[derive(Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ProductionInput {
+    pub resource: String,
+    pub amount: u32,
+    #[serde(default = "default_per", deserialize_with = "deserialize_per")]
+    pub per: u32,
+}
+
+fn default_per() -> u32 {
+    1
+}
+
+fn deserialize_per<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let per = u32::deserialize(deserializer)?;
+    if per == 0 {
+        return Err(serde::de::Error::custom("per has to be at least 1"));
+    }
+
+    Ok(per)
+}
+
+fn base_price(resources: &[Resource], resource: &str) -> i32 {
+    if resource == COIN_NAME {
+        1
+    } else {
+        resources
+            .iter()
+            .find(|i| i.name == resource)
+            .unwrap()
+            .base_price as i32
+    }
+}
+
+impl Production {
+    /// value of a year of full production minus the value of its inputs
+    pub fn yearly_value(&self, resources: &[Resource]) -> i32 {
+        let yearly = 2 * self.amount.growth
+            + self.amount.summer
+            + self.amount.harvest
+            + 2 * self.amount.winter;
+
+        self.inputs.iter().fold(
+            yearly as i32 * base_price(resources, &self.resource),
+            |acc, input| {
+                acc - input.consumption(yearly) as i32 * base_price(resources, &input.resource)
+            },
+        )
+    }
+}
+
+impl ProductionInput {
+    /// how many units can be produced with the given stock of this input
+    pub fn max_output(&self, stock: u32) -> u32 {
+        (stock * self.per)
+            .checked_div(self.amount)
+            .unwrap_or(u32::MAX)
+    }
+
+    pub fn consumption(&self, output: u32) -> u32 {
+        (output * self.amount + self.per - 1) / self.per
+    }
+}
+
+impl Settlement {
+    fn stock(&mut self, resource: &str) -> &mut u32 {
+        if resource == COIN_NAME {
+            &mut self.silver
+        } else {
+            self.resources.entry(resource.to_owned()).or_default()
+        }
+    }
+
+    pub fn production_tick(&mut self, time: &GameTime, populations: &[Population]) {
+        let mut tick_production = HashMap::new();
+
+        for population in self.populations.clone() {
+            let population = populations.iter().find(|i| i.name == population).unwrap();
+
+            for production in population.production.iter() {
+                let modifier = self.modifier(ModifierKind::Production, &production.resource);
+                let planned = (production.amount.value(time) as f32 * modifier).round() as u32;
+
+                // output is scaled down to what the inputs in stock allow
+                let amount = production.inputs.iter().fold(planned, |amount, input| {
+                    amount.min(input.max_output(*self.stock(&input.resource)))
+                });
+
+                for input in production.inputs.iter() {
+                    *self.stock(&input.resource) -= input.consumption(amount);
+                }
+
+                let resource = self.stock(&production.resource);
+
+                *tick_production
+                    .entry(production.resource.clone())
+                    .or_default() += amount;
+
+                *resource += amount;
+            }
+        }
+
+        self.track_production_tick(tick_production);
+    }
+}
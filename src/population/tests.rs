@@ -0,0 +1,81 @@
+use super::*;
+use crate::settlement::SettlementModifier;
+
+fn setup(stock: &[(&str, u32)]) -> (Settlement, Vec<Population>) {
+    let settlement = Settlement::test_city("Sinope", &["Tailor"], stock);
+
+    let populations: Vec<Population> = serde_yaml::from_str(
+        "
+- name: Tailor
+  production:
+    - resource: Clothing
+      amount:
+        growth: 6
+      inputs:
+        - resource: Wool
+          amount: 1
+          per: 2
+",
+    )
+    .unwrap();
+
+    (settlement, populations)
+}
+
+fn growth_season() -> GameTime {
+    GameTime { year: 1, season: 1 }
+}
+
+#[test]
+fn consumes_inputs() {
+    let (mut settlement, populations) = setup(&[("Wool", 10)]);
+
+    settlement.production_tick(&growth_season(), &populations);
+
+    assert_eq!(settlement.resources.get("Clothing"), Some(&6));
+    assert_eq!(settlement.resources.get("Wool"), Some(&7));
+}
+
+#[test]
+fn scales_down_output_on_short_inputs() {
+    let (mut settlement, populations) = setup(&[("Wool", 2)]);
+
+    settlement.production_tick(&growth_season(), &populations);
+
+    assert_eq!(settlement.resources.get("Clothing"), Some(&4));
+    assert_eq!(settlement.resources.get("Wool"), Some(&0));
+}
+
+#[test]
+fn produces_nothing_without_inputs() {
+    let (mut settlement, populations) = setup(&[("Cloth", 2)]);
+
+    settlement.production_tick(&growth_season(), &populations);
+
+    assert_eq!(settlement.resources.get("Clothing"), Some(&0));
+    assert_eq!(settlement.resources.get("Cloth"), Some(&2));
+}
+
+#[test]
+fn production_modifiers_scale_output() {
+    let (mut settlement, populations) = setup(&[("Wool", 10)]);
+    settlement.add_modifier(SettlementModifier {
+        name: "Plague among the sheep".to_owned(),
+        kind: ModifierKind::Production,
+        resource: Some("Clothing".to_owned()),
+        multiplier: 0.5,
+        seasons: 2,
+    });
+
+    settlement.production_tick(&growth_season(), &populations);
+
+    assert_eq!(settlement.resources.get("Clothing"), Some(&3));
+    assert_eq!(settlement.resources.get("Wool"), Some(&8));
+}
+
+#[test]
+fn rejects_zero_per_in_inputs() {
+    let input = serde_yaml::from_str::<ProductionInput>("{resource: Wool, amount: 1, per: 0}");
+
+    assert!(input.is_err());
+}
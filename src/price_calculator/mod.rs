@@ -0,0 +1,147 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{
+    game_time::GameTimeAdvancedEvent,
+    resources::Resource,
+    settlement::{ModifierKind, Settlement},
+};
+
+#[cfg(test)]
+mod tests;
+
+pub struct PriceCalculator {
+    pub base_price: u32,
+    pub demand: u32,
+    pub supply: u32,
+}
+
+const MIN_SHORTAGE_MOD: f32 = 0.8;
+const MAX_SHORTAGE_MOD: f32 = 2.0;
+
+impl PriceCalculator {
+    fn shortage_mod(&self) -> f32 {
+        let res = (self.demand as f32 - self.supply as f32) / self.demand as f32;
+
+        f32::clamp(1. + res, MIN_SHORTAGE_MOD, MAX_SHORTAGE_MOD)
+    }
+
+    /// price for which player can buy
+    pub fn buy_price(&self) -> u32 {
+        (self.base_price as f32 * self.shortage_mod()).ceil() as u32
+    }
+
+    /// price for which player can sell
+    pub fn sell_price(&self) -> u32 {
+        let price = Self {
+            base_price: self.base_price,
+            demand: self.demand,
+            supply: self.supply + 1,
+        };
+
+        // we would buy for price we could sell it at if we had one more
+        price.buy_price()
+    }
+
+    fn with_supply(&self, supply: u32) -> Self {
+        Self {
+            base_price: self.base_price,
+            demand: self.demand,
+            supply,
+        }
+    }
+
+    /// total price for buying several units, as every unit bought raises
+    /// the price of the next one
+    pub fn buy_total(&self, amount: u32) -> u32 {
+        (0..amount)
+            .map(|bought| {
+                self.with_supply(self.supply.saturating_sub(bought))
+                    .buy_price()
+            })
+            .sum()
+    }
+
+    /// total price for selling several units, as every unit sold lowers
+    /// the price of the next one
+    pub fn sell_total(&self, amount: u32) -> u32 {
+        (0..amount)
+            .map(|sold| self.with_supply(self.supply + sold).sell_price())
+            .sum()
+    }
+
+    /// how many units, up to the limit, can be bought with the silver
+    pub fn max_buy(&self, silver: u32, limit: u32) -> u32 {
+        let mut total = 0;
+
+        for bought in 0..limit {
+            total += self
+                .with_supply(self.supply.saturating_sub(bought))
+                .buy_price();
+            if total > silver {
+                return bought;
+            }
+        }
+
+        limit
+    }
+
+    /// how many units, up to the limit, can be sold for the silver available
+    pub fn max_sell(&self, silver: u32, limit: u32) -> u32 {
+        let mut total = 0;
+
+        for sold in 0..limit {
+            total += self.with_supply(self.supply + sold).sell_price();
+            if total > silver {
+                return sold;
+            }
+        }
+
+        limit
+    }
+}
+
+impl Settlement {
+    /// current local prices of a resource
+    pub fn prices(&self, resource: &Resource) -> PriceCalculator {
+        let modifier = self.modifier(ModifierKind::Price, &resource.name);
+
+        PriceCalculator {
+            base_price: (resource.base_price as f32 * modifier).round() as u32,
+            demand: self.demand(resource).ceil() as u32,
+            supply: *self.resources.get(&resource.name).unwrap_or(&0),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct AveragePrices {
+    pub prices: HashMap<String, f32>,
+}
+
+pub fn average_prices(
+    settlements: Query<&Settlement>,
+    mut average_prices: ResMut<AveragePrices>,
+    resources: Option<Res<Vec<Resource>>>,
+    events: EventReader<GameTimeAdvancedEvent>,
+) {
+    if events.is_empty() || resources.is_none() {
+        return;
+    }
+
+    let resources = resources.unwrap();
+
+    let settlement_count = settlements.iter().len() as f32;
+
+    for resource in resources.iter() {
+        let sum = settlements.iter().fold(0.0, |acc, settlement| {
+            acc + settlement.prices(resource).sell_price() as f32
+        });
+
+        *average_prices
+            .prices
+            .entry(resource.name.clone())
+            .or_default() = sum / settlement_count;
+    }
+}
@@ -0,0 +1,47 @@
+use super::*;
+
+fn prices() -> PriceCalculator {
+    PriceCalculator {
+        base_price: 10,
+        demand: 10,
+        supply: 10,
+    }
+}
+
+#[test]
+fn single_unit_totals_match_prices() {
+    let prices = prices();
+
+    assert_eq!(prices.buy_total(1), prices.buy_price());
+    assert_eq!(prices.sell_total(1), prices.sell_price());
+    assert_eq!(prices.buy_total(0), 0);
+}
+
+#[test]
+fn buying_raises_the_price_of_each_unit() {
+    let prices = prices();
+
+    // 10, 11, 12 as supply drops to 9 and 8
+    assert_eq!(prices.buy_total(3), 33);
+    assert!(prices.buy_total(5) > 5 * prices.buy_price());
+}
+
+#[test]
+fn selling_lowers_the_price_of_each_unit() {
+    let prices = prices();
+
+    // 9, 8 as supply rises to 11 and 12
+    assert_eq!(prices.sell_total(2), 17);
+    assert!(prices.sell_total(5) < 5 * prices.sell_price());
+}
+
+#[test]
+fn limits_amounts_by_silver() {
+    let prices = prices();
+
+    assert_eq!(prices.max_buy(33, 10), 3);
+    assert_eq!(prices.max_buy(32, 10), 2);
+    assert_eq!(prices.max_buy(1000, 4), 4);
+    assert_eq!(prices.max_sell(17, 10), 2);
+    assert_eq!(prices.max_sell(0, 10), 0);
+}
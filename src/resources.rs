@@ -8,4 +8,7 @@ pub struct Resource {
     pub base_price: u32,
     pub demand: CalculatedPopulationValue,
     pub max: CalculatedPopulationValue,
+    /// only ships with at least this hull can carry the resource
+    #[serde(default)]
+    pub min_hull: u32,
 }
@@ -0,0 +1,203 @@
+use super::{LoadGameEvent, PendingLoadGame, SaveGame, SaveGameFile};
+use crate::{
+    building::{Shipyard, Temple},
+    game_events::GameEventsState,
+    game_rng::GameRng,
+    game_time::GameTime,
+    merchant::MerchantConvoy,
+    price_calculator::AveragePrices,
+    settlement::Settlement,
+    types::Player,
+};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+pub fn apply_save_game(
+    save: SaveGame,
+    settlements: &mut Query<(Entity, &mut Settlement)>,
+    buildings: (&mut Query<&mut Temple>, &mut Query<&mut Shipyard>),
+    player: &mut Player,
+    merchants: (&mut Commands, &Query<Entity, With<MerchantConvoy>>),
+    world: (
+        &mut GameTime,
+        &mut GameEventsState,
+        &mut AveragePrices,
+        &mut GameRng,
+    ),
+) {
+    let (temples, shipyards) = buildings;
+    let (commands, merchant_entities) = merchants;
+    let (game_time, events_state, average_prices, rng) = world;
+
+    let mut building_entities: HashMap<(String, usize), Entity> = HashMap::new();
+    let mut location = None;
+    let mut saved_settlements: HashMap<String, Settlement> = save
+        .settlements
+        .into_iter()
+        .map(|settlement| (settlement.name.clone(), settlement))
+        .collect();
+
+    for (entity, mut settlement) in settlements.iter_mut() {
+        if save.player_location.as_ref() == Some(&settlement.name) {
+            location = Some((
+                entity,
+                Vec2::new(settlement.position.x as f32, settlement.position.y as f32),
+            ));
+        }
+
+        for (index, building) in settlement.buildings.iter().enumerate() {
+            if let Some(building_entity) = building.entity {
+                building_entities.insert((settlement.name.clone(), index), building_entity);
+            }
+        }
+
+        if let Some(saved) = saved_settlements.remove(&settlement.name) {
+            settlement.restore(saved);
+        } else {
+            log::warn!("settlement {} missing in save game", settlement.name);
+        }
+    }
+
+    for saved in save.temples.into_iter() {
+        if let Some(entity) = building_entities.get(&(saved.settlement, saved.index)) {
+            if let Ok(mut temple) = temples.get_mut(*entity) {
+                *temple = saved.building;
+            }
+        }
+    }
+
+    for saved in save.shipyards.into_iter() {
+        if let Some(entity) = building_entities.get(&(saved.settlement, saved.index)) {
+            if let Ok(mut shipyard) = shipyards.get_mut(*entity) {
+                *shipyard = saved.building;
+            }
+        }
+    }
+
+    *player = Player {
+        location_marker: player.location_marker,
+        location_marker_texture_atlas_handle: player.location_marker_texture_atlas_handle.clone(),
+        ..save.player
+    };
+    match location {
+        Some((entity, position)) => player.update_position(position, Some(entity)),
+        None => log::warn!("player location missing in save game"),
+    }
+
+    for entity in merchant_entities.iter() {
+        commands.entity(entity).despawn();
+    }
+    for merchant in save.merchants.into_iter() {
+        commands.spawn().insert(merchant);
+    }
+
+    rng.resume(save.seed, &save.game_time);
+    *game_time = save.game_time;
+    *events_state = save.events;
+    *average_prices = save.average_prices;
+}
+
+#[allow(clippy::type_complexity)]
+pub fn load_save_game(
+    mut events: EventReader<LoadGameEvent>,
+    mut merchants: (Commands, Query<Entity, With<MerchantConvoy>>),
+    file: Res<SaveGameFile>,
+    mut settlements: Query<(Entity, &mut Settlement)>,
+    mut buildings: (Query<&mut Temple>, Query<&mut Shipyard>),
+    mut state: (
+        ResMut<GameTime>,
+        Option<ResMut<Player>>,
+        ResMut<GameEventsState>,
+        ResMut<AveragePrices>,
+    ),
+    mut rng: ResMut<GameRng>,
+) {
+    if events.iter().count() == 0 {
+        return;
+    }
+
+    load_from_file(
+        &file.0,
+        &mut settlements,
+        &mut buildings,
+        &mut merchants,
+        &mut state,
+        &mut rng,
+    );
+}
+
+#[allow(clippy::type_complexity)]
+pub fn load_pending_save_game(
+    mut merchants: (Commands, Query<Entity, With<MerchantConvoy>>),
+    pending: Option<Res<PendingLoadGame>>,
+    file: Res<SaveGameFile>,
+    mut settlements: Query<(Entity, &mut Settlement)>,
+    mut buildings: (Query<&mut Temple>, Query<&mut Shipyard>),
+    mut state: (
+        ResMut<GameTime>,
+        Option<ResMut<Player>>,
+        ResMut<GameEventsState>,
+        ResMut<AveragePrices>,
+    ),
+    mut rng: ResMut<GameRng>,
+) {
+    let (_, player, _, _) = &state;
+    if pending.is_none() || settlements.is_empty() || player.is_none() {
+        return;
+    }
+
+    // a save game that fails to load falls back to warming up a new game
+    merchants.0.remove_resource::<PendingLoadGame>();
+    load_from_file(
+        &file.0,
+        &mut settlements,
+        &mut buildings,
+        &mut merchants,
+        &mut state,
+        &mut rng,
+    );
+}
+
+/// reads the save game from the file and applies it, a broken file keeps the current game
+#[allow(clippy::type_complexity)]
+fn load_from_file(
+    file: &str,
+    settlements: &mut Query<(Entity, &mut Settlement)>,
+    buildings: &mut (Query<&mut Temple>, Query<&mut Shipyard>),
+    merchants: &mut (Commands, Query<Entity, With<MerchantConvoy>>),
+    state: &mut (
+        ResMut<GameTime>,
+        Option<ResMut<Player>>,
+        ResMut<GameEventsState>,
+        ResMut<AveragePrices>,
+    ),
+    rng: &mut GameRng,
+) {
+    let (temples, shipyards) = buildings;
+    let (commands, merchant_entities) = merchants;
+    let (game_time, player, events_state, average_prices) = state;
+    let player = match player {
+        Some(player) => player,
+        None => return,
+    };
+
+    match SaveGame::read(file) {
+        Ok(save) => {
+            apply_save_game(
+                save,
+                settlements,
+                (temples, shipyards),
+                player,
+                (commands, &*merchant_entities),
+                (
+                    &mut **game_time,
+                    &mut **events_state,
+                    &mut **average_prices,
+                    rng,
+                ),
+            );
+            log::info!("loaded game from {}", file);
+        }
+        Err(error) => log::error!("failed to load game from {}: {}", file, error),
+    }
+}
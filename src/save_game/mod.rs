@@ -0,0 +1,103 @@
+use crate::{
+    building::{Shipyard, Temple},
+    game_events::GameEventsState,
+    game_time::GameTime,
+    merchant::MerchantConvoy,
+    price_calculator::AveragePrices,
+    settlement::Settlement,
+    types::Player,
+};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+
+mod load_save_game;
+mod write_save_game;
+
+#[cfg(test)]
+mod tests;
+
+pub use load_save_game::apply_save_game;
+
+/// bump whenever the save format changes in an incompatible way
+pub const SAVE_GAME_VERSION: u32 = 5;
+const DEFAULT_SAVE_GAME_FILE: &str = "pondos.save";
+
+pub struct SaveGameEvent;
+pub struct LoadGameEvent;
+
+/// file the game is saved to and loaded from
+pub struct SaveGameFile(pub String);
+
+impl Default for SaveGameFile {
+    fn default() -> Self {
+        Self(DEFAULT_SAVE_GAME_FILE.to_owned())
+    }
+}
+
+/// present while a save game should replace the warm-up of a new game
+pub struct PendingLoadGame;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SavedBuilding<T> {
+    pub settlement: String,
+    pub index: usize,
+    pub building: T,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SaveGame {
+    pub version: u32,
+    pub seed: u64,
+    pub game_time: GameTime,
+    pub player: Player,
+    pub player_location: Option<String>,
+    pub settlements: Vec<Settlement>,
+    pub temples: Vec<SavedBuilding<Temple>>,
+    pub shipyards: Vec<SavedBuilding<Shipyard>>,
+    pub events: GameEventsState,
+    pub average_prices: AveragePrices,
+    #[serde(default)]
+    pub merchants: Vec<MerchantConvoy>,
+}
+
+#[derive(Deserialize)]
+struct SaveGameHeader {
+    version: u32,
+}
+
+impl SaveGame {
+    pub fn write(&self, path: &str) -> Result<(), String> {
+        let file = File::create(path).map_err(|e| e.to_string())?;
+        serde_yaml::to_writer(file, self).map_err(|e| e.to_string())
+    }
+
+    pub fn read(path: &str) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+
+        let header: SaveGameHeader = serde_yaml::from_str(&content).map_err(|e| e.to_string())?;
+        if header.version != SAVE_GAME_VERSION {
+            return Err(format!(
+                "save game version {} is not supported (expected {})",
+                header.version, SAVE_GAME_VERSION
+            ));
+        }
+
+        serde_yaml::from_str(&content).map_err(|e| e.to_string())
+    }
+}
+
+pub struct SaveGamePlugin;
+
+impl Plugin for SaveGamePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SaveGameFile>()
+            .add_event::<SaveGameEvent>()
+            .add_event::<LoadGameEvent>()
+            .add_system(write_save_game::write_save_game)
+            .add_system(load_save_game::load_save_game)
+            .add_system(load_save_game::load_pending_save_game);
+    }
+}
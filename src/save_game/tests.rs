@@ -0,0 +1,90 @@
+use super::*;
+use crate::{
+    building::{Building, BuildingType, ShipyardInfo},
+    types::{Ship, ShipClass},
+};
+use std::collections::HashMap;
+
+fn save_game() -> SaveGame {
+    let mut settlement = Settlement::test_city("Olbia", &["Farmer"], &[("Grain", 12)]);
+    settlement.silver = 420;
+    settlement
+        .buildings
+        .push(Building::from(BuildingType::Shipyard(ShipyardInfo {
+            ships: vec!["Small Ship".to_owned()],
+            ..default()
+        })));
+    settlement.track_production_tick([("Grain".to_owned(), 4)].into_iter().collect());
+
+    let mut resources = HashMap::new();
+    resources.insert("Wine".to_owned(), 3);
+
+    SaveGame {
+        version: SAVE_GAME_VERSION,
+        seed: 42,
+        game_time: GameTime { year: 3, season: 4 },
+        player: Player {
+            silver: 1234,
+            resources,
+            convoy: vec![Ship::new(&ShipClass::test_class("Medium Ship", 50))],
+            ..default()
+        },
+        player_location: Some("Olbia".to_owned()),
+        settlements: vec![settlement],
+        temples: vec![],
+        shipyards: vec![SavedBuilding {
+            settlement: "Olbia".to_owned(),
+            index: 0,
+            building: Shipyard {
+                orders: vec![crate::building::ShipyardOrder {
+                    task: crate::building::ShipyardTask::Construction(ShipClass::test_class(
+                        "Large Ship",
+                        100,
+                    )),
+                    time_remaining: 2,
+                }],
+                ..default()
+            },
+        }],
+        events: GameEventsState::default(),
+        average_prices: AveragePrices::default(),
+        merchants: vec![],
+    }
+}
+
+#[test]
+fn round_trips_save_game() {
+    let path = std::env::temp_dir().join("pondos-round-trip.save");
+    let path = path.to_str().unwrap();
+
+    save_game().write(path).unwrap();
+    let save = SaveGame::read(path).unwrap();
+
+    assert_eq!(save.seed, 42);
+    assert_eq!(save.game_time.year, 3);
+    assert_eq!(save.game_time.season, 4);
+    assert_eq!(save.player.silver, 1234);
+    assert_eq!(save.player.resources.get("Wine"), Some(&3));
+    assert_eq!(save.player.convoy[0].class.name, "Medium Ship");
+    assert_eq!(save.player_location, Some("Olbia".to_owned()));
+
+    let settlement = &save.settlements[0];
+    assert_eq!(settlement.silver, 420);
+    assert_eq!(settlement.resources.get("Grain"), Some(&12));
+    assert_eq!(settlement.buildings.len(), 1);
+    assert_eq!(settlement.produced_items(), vec!["Grain".to_owned()]);
+
+    assert_eq!(save.shipyards[0].building.orders[0].time_remaining, 2);
+}
+
+#[test]
+fn rejects_other_save_game_versions() {
+    let path = std::env::temp_dir().join("pondos-version.save");
+    let path = path.to_str().unwrap();
+
+    let mut save = save_game();
+    save.version = SAVE_GAME_VERSION + 1;
+    save.write(path).unwrap();
+
+    assert!(SaveGame::read(path).is_err());
+}
@@ -0,0 +1,88 @@
+use super::{SaveGame, SaveGameEvent, SaveGameFile, SavedBuilding, SAVE_GAME_VERSION};
+use crate::{
+    building::{Shipyard, Temple},
+    game_events::GameEventsState,
+    game_rng::GameRng,
+    game_time::GameTime,
+    merchant::MerchantConvoy,
+    price_calculator::AveragePrices,
+    settlement::Settlement,
+    types::Player,
+};
+use bevy::prelude::*;
+
+pub fn write_save_game(
+    mut events: EventReader<SaveGameEvent>,
+    file: Res<SaveGameFile>,
+    settlements: Query<(Entity, &Settlement)>,
+    buildings: (Query<&Temple>, Query<&Shipyard>),
+    state: (
+        Res<GameTime>,
+        Option<Res<Player>>,
+        Res<GameEventsState>,
+        Res<AveragePrices>,
+    ),
+    merchants: Query<&MerchantConvoy>,
+    rng: Res<GameRng>,
+) {
+    if events.iter().count() == 0 {
+        return;
+    }
+
+    let (temples, shipyards) = buildings;
+    let (game_time, player, events_state, average_prices) = state;
+
+    let player = match player {
+        Some(player) => player,
+        None => {
+            log::error!("cannot save game before player is loaded");
+            return;
+        }
+    };
+
+    let mut save = SaveGame {
+        version: SAVE_GAME_VERSION,
+        seed: rng.seed(),
+        game_time: game_time.clone(),
+        player: player.clone(),
+        player_location: None,
+        settlements: vec![],
+        temples: vec![],
+        shipyards: vec![],
+        events: events_state.clone(),
+        average_prices: average_prices.clone(),
+        merchants: merchants.iter().cloned().collect(),
+    };
+
+    for (entity, settlement) in settlements.iter() {
+        if player.location == Some(entity) {
+            save.player_location = Some(settlement.name.clone());
+        }
+
+        for (index, building) in settlement.buildings.iter().enumerate() {
+            if let Some(entity) = building.entity {
+                if let Ok(temple) = temples.get(entity) {
+                    save.temples.push(SavedBuilding {
+                        settlement: settlement.name.clone(),
+                        index,
+                        building: temple.clone(),
+                    });
+                }
+                if let Ok(shipyard) = shipyards.get(entity) {
+                    save.shipyards.push(SavedBuilding {
+                        settlement: settlement.name.clone(),
+                        index,
+                        building: shipyard.clone(),
+                    });
+                }
+            }
+        }
+
+        save.settlements.push(settlement.clone());
+    }
+
+    match save.write(&file.0) {
+        Ok(()) => log::info!("saved game to {}", file.0),
+        Err(error) => log::error!("failed to save game to {}: {}", file.0, error),
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     game_time::{GameTime, GameTimeAdvancedEvent},
     resources::Resource,
-    settlement::Settlement,
+    settlement::{ModifierKind, Settlement},
     Settings,
 };
 use bevy::prelude::*;
@@ -54,7 +54,9 @@ impl Settlement {
         }
 
         for resource in resources.iter() {
-            let max = resource.max.value(&self.populations).ceil() as u32;
+            let max = (resource.max.value(&self.populations)
+                * self.modifier(ModifierKind::Max, &resource.name))
+            .ceil() as u32;
 
             cap_resource(
                 self.resources.entry(resource.name.clone()).or_default(),
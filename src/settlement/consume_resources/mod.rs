@@ -0,0 +1,60 @@
+use crate::{
+    game_time::{GameTime, GameTimeAdvancedEvent},
+    resources::Resource,
+    settlement::Settlement,
+    Settings,
+};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests;
+
+pub fn consume_resources(
+    mut settlements: Query<&mut Settlement>,
+    mut events: EventReader<GameTimeAdvancedEvent>,
+    resources: Option<Res<Vec<Resource>>>,
+    settings: Option<Res<Settings>>,
+) {
+    if let Some(resources) = resources {
+        if let Some(settings) = settings {
+            for event in events.iter() {
+                for mut settlement in settlements.iter_mut() {
+                    settlement.consumption_tick(&event.time, &resources, &settings);
+                }
+            }
+        }
+    }
+}
+
+impl Settlement {
+    /// Removes the seasonal share of the demand from the stock. Whatever
+    /// could not be served is tracked as shortage.
+    pub fn consumption_tick(
+        &mut self,
+        time: &GameTime,
+        resources: &[Resource],
+        settings: &Settings,
+    ) {
+        let multiplier = settings.consumption_multipliers.value(time);
+        let mut shortages = HashMap::new();
+
+        for resource in resources.iter() {
+            let wanted = (self.demand(resource) * multiplier).round() as u32;
+            let consumed = match self.resources.get_mut(&resource.name) {
+                Some(stock) => {
+                    let consumed = wanted.min(*stock);
+                    *stock -= consumed;
+                    consumed
+                }
+                None => 0,
+            };
+
+            if consumed < wanted {
+                shortages.insert(resource.name.clone(), wanted - consumed);
+            }
+        }
+
+        self.track_shortage_tick(shortages);
+    }
+}
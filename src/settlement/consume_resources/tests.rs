@@ -0,0 +1,86 @@
+use super::*;
+use crate::settlement::TRACK_SHORTAGE_TICKS;
+
+fn setup(stock: u32) -> (Settlement, Vec<Resource>) {
+    let settlement = Settlement::test_city("Histria", &["Farmer"; 4], &[("Grain", stock)]);
+
+    let resources: Vec<Resource> = serde_yaml::from_str(
+        "
+- name: Grain
+  base_price: 10
+  demand:
+    Population: 10
+  max:
+    Population: 100
+",
+    )
+    .unwrap();
+
+    (settlement, resources)
+}
+
+fn growth_season() -> GameTime {
+    GameTime { year: 1, season: 1 }
+}
+
+#[test]
+fn consumes_the_demand() {
+    let (mut settlement, resources) = setup(100);
+
+    settlement.consumption_tick(&growth_season(), &resources, &Settings::test_settings());
+
+    assert_eq!(settlement.resources.get("Grain"), Some(&60));
+    assert_eq!(settlement.last_shortage("Grain"), 0);
+    assert!(settlement.shortages().is_empty());
+}
+
+#[test]
+fn tracks_unmet_demand_as_shortage() {
+    let (mut settlement, resources) = setup(10);
+    let settings = Settings::test_settings();
+
+    settlement.consumption_tick(&growth_season(), &resources, &settings);
+    assert_eq!(settlement.resources.get("Grain"), Some(&0));
+    assert_eq!(settlement.last_shortage("Grain"), 30);
+
+    settlement.consumption_tick(&growth_season(), &resources, &settings);
+    assert_eq!(settlement.last_shortage("Grain"), 40);
+    assert_eq!(settlement.shortages(), vec![("Grain".to_owned(), 70)]);
+}
+
+#[test]
+fn forgets_old_shortages() {
+    let (mut settlement, resources) = setup(0);
+    let settings = Settings::test_settings();
+
+    for _ in 0..TRACK_SHORTAGE_TICKS + 2 {
+        settlement.consumption_tick(&growth_season(), &resources, &settings);
+    }
+
+    assert_eq!(settlement.shortage_last_ticks.len(), TRACK_SHORTAGE_TICKS);
+    assert_eq!(
+        settlement.shortages(),
+        vec![("Grain".to_owned(), 40 * TRACK_SHORTAGE_TICKS as u32)]
+    );
+}
+
+#[test]
+fn consumes_less_in_winter() {
+    let (mut settlement, resources) = setup(100);
+    let winter = GameTime { year: 1, season: 0 };
+
+    settlement.consumption_tick(&winter, &resources, &Settings::test_settings());
+
+    assert_eq!(settlement.resources.get("Grain"), Some(&80));
+}
+
+#[test]
+fn counts_missing_goods_as_shortage() {
+    let (mut settlement, resources) = setup(0);
+    settlement.resources.clear();
+
+    settlement.consumption_tick(&growth_season(), &resources, &Settings::test_settings());
+
+    assert_eq!(settlement.resources.get("Grain"), None);
+    assert_eq!(settlement.last_shortage("Grain"), 40);
+}
@@ -0,0 +1,184 @@
+use crate::{
+    game_rng::GameRng,
+    game_time::{GameTime, GameTimeAdvancedEvent},
+    resources::Resource,
+    settlement::{Settlement, TRACK_PROSPERITY_TICKS},
+    Settings,
+};
+use bevy::prelude::*;
+use rand::{seq::SliceRandom, Rng};
+use serde::Deserialize;
+use std::ops::DerefMut;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct DemographicsSettings {
+    /// resources that feed the population
+    pub food: Vec<String>,
+    /// average prosperity over the last year above which a settlement grows
+    pub growth_threshold: f32,
+    /// average prosperity over the last year below which a settlement shrinks
+    pub decline_threshold: f32,
+    /// how many tiles far people leaving a settlement are willing to move
+    pub migration_distance: f32,
+    /// settlements never shrink below this amount of populations
+    pub min_population: usize,
+}
+
+impl DemographicsSettings {
+    /// how the people of a settlement with the prosperity fare
+    pub fn outlook(&self, prosperity: f32) -> &'static str {
+        if prosperity >= self.growth_threshold {
+            "The people are thriving"
+        } else if prosperity >= 0. {
+            "The people are content"
+        } else if prosperity > self.decline_threshold {
+            "The people are struggling"
+        } else {
+            "The people are starving"
+        }
+    }
+}
+
+pub fn demographics(
+    mut settlements: Query<&mut Settlement>,
+    mut events: EventReader<GameTimeAdvancedEvent>,
+    resources: Option<Res<Vec<Resource>>>,
+    settings: Option<Res<Settings>>,
+    mut rng: ResMut<GameRng>,
+) {
+    if resources.is_none() || settings.is_none() {
+        return;
+    }
+    let resources = resources.unwrap();
+    let settings = settings.unwrap();
+
+    for event in events.iter() {
+        for mut settlement in settlements.iter_mut() {
+            settlement.prosperity_tick(&event.time, &resources, &settings);
+        }
+
+        // populations change once a year, at the end of winter
+        if event.time.is_initialized() && event.time.season == 0 {
+            let mut all: Vec<Mut<Settlement>> = settlements.iter_mut().collect();
+            population_change(&mut all, &settings.demographics, &mut *rng);
+        }
+    }
+}
+
+fn population_change<S: DerefMut<Target = Settlement>>(
+    settlements: &mut [S],
+    demographics: &DemographicsSettings,
+    rng: &mut GameRng,
+) {
+    let prosperities: Vec<(usize, Vec2, f32)> = settlements
+        .iter()
+        .enumerate()
+        .map(|(index, settlement)| {
+            (
+                index,
+                Vec2::new(settlement.position.x as f32, settlement.position.y as f32),
+                settlement.prosperity(),
+            )
+        })
+        .collect();
+
+    for (index, position, prosperity) in prosperities.iter() {
+        if *prosperity >= demographics.growth_threshold {
+            let settlement = &mut settlements[*index];
+
+            if let Some(population) = settlement.populations.choose(rng).cloned() {
+                log::info!("{} grows by a {}", settlement.name, population);
+                settlement.add_population(population);
+            }
+        } else if *prosperity <= demographics.decline_threshold {
+            let population = {
+                let settlement = &mut settlements[*index];
+                if settlement.populations.len() <= demographics.min_population {
+                    continue;
+                }
+
+                let removed = rng.gen_range(0..settlement.populations.len());
+                let population = settlement.populations.remove(removed);
+                log::info!("{} loses a {}", settlement.name, population);
+                population
+            };
+
+            // people leave for the most prosperous neighbour in reach
+            let destination = prosperities
+                .iter()
+                .filter(|(other, other_position, other_prosperity)| {
+                    other != index
+                        && *other_prosperity >= demographics.growth_threshold
+                        && position.distance(*other_position) <= demographics.migration_distance
+                })
+                .max_by(|a, b| a.2.partial_cmp(&b.2).unwrap());
+
+            if let Some((destination, _, _)) = destination {
+                let settlement = &mut settlements[*destination];
+                log::info!("a {} migrated to {}", population, settlement.name);
+                settlement.add_population(population);
+            }
+        }
+    }
+}
+
+impl Settlement {
+    pub fn add_population(&mut self, population: String) {
+        self.populations.push(population);
+        self.populations.sort();
+    }
+
+    /// Rates the last season between food supply, wealth and unmet demand.
+    /// Positive values attract people, negative ones drive them away.
+    pub fn prosperity_tick(
+        &mut self,
+        time: &GameTime,
+        resources: &[Resource],
+        settings: &Settings,
+    ) {
+        let demographics = &settings.demographics;
+
+        let (food_stock, food_demand) = resources
+            .iter()
+            .filter(|resource| demographics.food.contains(&resource.name))
+            .fold((0., 0.), |(stock, demand), resource| {
+                (
+                    stock + *self.resources.get(&resource.name).unwrap_or(&0) as f32,
+                    demand + self.demand(resource),
+                )
+            });
+        let food = (food_stock / f32::max(food_demand, 1.) - 1.).clamp(-1., 1.);
+
+        let max_silver = f32::max(settings.max_silver.value(&self.populations), 1.);
+        let wealth = f32::min(self.silver as f32 / max_silver, 1.) - 0.5;
+
+        let multiplier = settings.consumption_multipliers.value(time);
+        let (missing, wanted) = resources
+            .iter()
+            .fold((0., 0.), |(missing, wanted), resource| {
+                (
+                    missing + self.last_shortage(&resource.name) as f32,
+                    wanted + self.demand(resource) * multiplier,
+                )
+            });
+        let shortage = missing / f32::max(wanted, 1.);
+
+        self.prosperity_last_ticks.push(food + wealth - shortage);
+        if self.prosperity_last_ticks.len() > TRACK_PROSPERITY_TICKS {
+            self.prosperity_last_ticks.remove(0);
+        }
+    }
+
+    /// average prosperity of the last seasons
+    pub fn prosperity(&self) -> f32 {
+        if self.prosperity_last_ticks.is_empty() {
+            return 0.;
+        }
+
+        self.prosperity_last_ticks.iter().sum::<f32>() / self.prosperity_last_ticks.len() as f32
+    }
+}
@@ -0,0 +1,94 @@
+use super::*;
+
+fn grain() -> Vec<Resource> {
+    serde_yaml::from_str(
+        "
+- name: Grain
+  base_price: 10
+  demand:
+    Population: 10
+  max:
+    Population: 100
+",
+    )
+    .unwrap()
+}
+
+fn growth_season() -> GameTime {
+    GameTime { year: 1, season: 1 }
+}
+
+fn settlement(name: &str, population: &str, count: usize, prosperity: f32) -> Settlement {
+    let mut settlement = Settlement::test_city(name, &vec![population; count], &[]);
+    settlement.prosperity_last_ticks = vec![prosperity];
+    settlement
+}
+
+#[test]
+fn prosperity_rates_food_wealth_and_shortages() {
+    let settings = Settings::test_settings();
+    let mut settlement = Settlement::test_city("Histria", &["Farmer"; 4], &[("Grain", 80)]);
+    settlement.silver = 400;
+
+    // twice the food needed and full coffers
+    settlement.prosperity_tick(&growth_season(), &grain(), &settings);
+    assert_eq!(settlement.prosperity(), 1.5);
+
+    // no food, no silver and none of the demand met
+    settlement.resources.insert("Grain".to_owned(), 0);
+    settlement.silver = 0;
+    settlement.track_shortage_tick([("Grain".to_owned(), 40)].into_iter().collect());
+    settlement.prosperity_tick(&growth_season(), &grain(), &settings);
+    assert_eq!(settlement.prosperity(), -0.5);
+}
+
+#[test]
+fn outlook_follows_the_thresholds() {
+    let settings = Settings::test_settings();
+    let demographics = &settings.demographics;
+
+    assert_eq!(demographics.outlook(0.5), "The people are thriving");
+    assert_eq!(demographics.outlook(0.4), "The people are content");
+    assert_eq!(demographics.outlook(-0.3), "The people are struggling");
+    assert_eq!(demographics.outlook(-0.4), "The people are starving");
+}
+
+#[test]
+fn prosperous_settlements_grow_and_attract_migrants() {
+    let settings = Settings::test_settings();
+    let mut settlements = vec![
+        settlement("Histria", "Farmer", 4, 1.),
+        settlement("Tomis", "Fisher", 4, -1.),
+        settlement("Kallatis", "Fisher", 3, -1.),
+        settlement("Odessos", "Potter", 4, 0.),
+    ];
+    let mut all: Vec<&mut Settlement> = settlements.iter_mut().collect();
+
+    population_change(&mut all, &settings.demographics, &mut GameRng::new(42));
+
+    assert_eq!(
+        settlements[0].populations,
+        vec!["Farmer", "Farmer", "Farmer", "Farmer", "Farmer", "Fisher"]
+    );
+    // one left for the thriving neighbour
+    assert_eq!(settlements[1].populations.len(), 3);
+    // already at the minimum
+    assert_eq!(settlements[2].populations.len(), 3);
+    assert_eq!(settlements[3].populations.len(), 4);
+}
+
+#[test]
+fn migrants_only_move_within_reach() {
+    let settings = Settings::test_settings();
+    let mut settlements = vec![
+        settlement("Histria", "Farmer", 4, 1.),
+        settlement("Tomis", "Fisher", 4, -1.),
+    ];
+    settlements[0].position = crate::settlement::Position { x: 100, y: 100 };
+    let mut all: Vec<&mut Settlement> = settlements.iter_mut().collect();
+
+    population_change(&mut all, &settings.demographics, &mut GameRng::new(42));
+
+    assert_eq!(settlements[0].populations.len(), 5);
+    assert_eq!(settlements[1].populations.len(), 3);
+}
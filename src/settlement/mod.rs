@@ -1,33 +1,43 @@
 use crate::{
     building::Building,
     game_state::{GameState, LoadingState, RunningState, SettlementState},
+    game_time::GameTime,
     COIN_NAME,
 };
 use bevy::prelude::*;
 use iyes_loopless::{condition::ConditionSystemSet, prelude::*};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+pub use modifiers::{ModifierKind, SettlementModifier};
+
 pub mod cap_resources;
+pub mod consume_resources;
+pub mod demographics;
+pub mod modifiers;
 mod settlement_ui;
+pub mod track_prices;
 mod trade_ui;
 mod travel_ui;
 mod ui;
 
 const TRACK_PRODUCTION_TICKS: usize = 8;
+const TRACK_SHORTAGE_TICKS: usize = 8;
+const TRACK_PROSPERITY_TICKS: usize = 6;
+pub const TRACK_PRICE_TICKS: usize = 12;
 
 pub struct VisitSettlementEvent {
     pub settlement: Entity,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum SettlementType {
     City,
     Outpost,
 }
 
-#[derive(Deserialize, Component, Debug)]
+#[derive(Deserialize, Serialize, Component, Debug, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct Settlement {
     pub name: String,
@@ -39,13 +49,74 @@ pub struct Settlement {
     #[serde(default)]
     pub resources: HashMap<String, u32>,
     pub populations: Vec<String>,
-    #[serde(default, deserialize_with = "crate::building::building_deserialize")]
+    #[serde(
+        default,
+        deserialize_with = "crate::building::building_deserialize",
+        serialize_with = "crate::building::building_serialize"
+    )]
     pub buildings: Vec<Building>,
     #[serde(default)]
     production_last_ticks: Vec<HashMap<String, u32>>,
+    #[serde(default)]
+    pub price_history: HashMap<String, Vec<PriceRecord>>,
+    #[serde(default)]
+    shortage_last_ticks: Vec<HashMap<String, u32>>,
+    #[serde(default)]
+    prosperity_last_ticks: Vec<f32>,
+    /// temporary changes to the economy caused by events
+    #[serde(default)]
+    pub modifiers: Vec<SettlementModifier>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct PriceRecord {
+    pub time: GameTime,
+    pub buy: u32,
+    pub sell: u32,
 }
 
+#[cfg(test)]
 impl Settlement {
+    /// city for tests with the given populations and goods in stock
+    pub fn test_city(name: &str, populations: &[&str], resources: &[(&str, u32)]) -> Self {
+        Self {
+            name: name.to_owned(),
+            settlement_type: SettlementType::City,
+            position: Position { x: 20, y: 36 },
+            silver: 0,
+            resources: resources
+                .iter()
+                .map(|(resource, amount)| (resource.to_string(), *amount))
+                .collect(),
+            populations: populations
+                .iter()
+                .map(|population| population.to_string())
+                .collect(),
+            buildings: vec![],
+            production_last_ticks: vec![],
+            price_history: HashMap::new(),
+            shortage_last_ticks: vec![],
+            prosperity_last_ticks: vec![],
+            modifiers: vec![],
+        }
+    }
+}
+
+impl Settlement {
+    /// overwrites the economic state with the one from a save game, while
+    /// keeping the building entities spawned for this session
+    pub fn restore(&mut self, saved: Settlement) {
+        self.silver = saved.silver;
+        self.resources = saved.resources;
+        self.populations = saved.populations;
+        self.production_last_ticks = saved.production_last_ticks;
+        self.price_history = saved.price_history;
+        self.shortage_last_ticks = saved.shortage_last_ticks;
+        self.prosperity_last_ticks = saved.prosperity_last_ticks;
+        self.modifiers = saved.modifiers;
+    }
+
     pub fn track_production_tick(&mut self, production: HashMap<String, u32>) {
         self.production_last_ticks.push(production);
         if self.production_last_ticks.len() > TRACK_PRODUCTION_TICKS {
@@ -53,6 +124,40 @@ impl Settlement {
         }
     }
 
+    pub fn track_shortage_tick(&mut self, shortages: HashMap<String, u32>) {
+        self.shortage_last_ticks.push(shortages);
+        if self.shortage_last_ticks.len() > TRACK_SHORTAGE_TICKS {
+            self.shortage_last_ticks.remove(0);
+        }
+    }
+
+    /// unmet demand of the last season
+    pub fn last_shortage(&self, resource: &str) -> u32 {
+        self.shortage_last_ticks
+            .last()
+            .and_then(|shortages| shortages.get(resource))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// unmet demand over the last seasons, largest shortage first
+    pub fn shortages(&self) -> Vec<(String, u32)> {
+        let all_shortages =
+            self.shortage_last_ticks
+                .iter()
+                .fold(HashMap::new(), |mut acc, items| {
+                    for (res, amount) in items.iter() {
+                        *acc.entry(res.clone()).or_default() += amount;
+                    }
+
+                    acc
+                });
+
+        let mut all_shortages: Vec<(String, u32)> = all_shortages.into_iter().collect();
+        all_shortages.sort_unstable_by_key(|item| (-(item.1 as i32), item.0.clone()));
+        all_shortages
+    }
+
     pub fn produced_items(&self) -> Vec<String> {
         let all_production =
             self.production_last_ticks
@@ -73,7 +178,7 @@ impl Settlement {
     }
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct Position {
     pub x: u32,
@@ -82,7 +187,10 @@ pub struct Position {
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, SystemLabel)]
 pub enum SettlementLabel {
+    Production,
+    Consumption,
     CapResources,
+    TrackPrices,
 }
 
 fn visit_settlement_handler(
@@ -112,7 +220,8 @@ fn build_set(game_state: GameState) -> ConditionSystemSet {
 
 impl Plugin for SettlementPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system(visit_settlement_handler)
+        app.init_resource::<trade_ui::TradeQuantity>()
+            .add_system(visit_settlement_handler)
             .add_system_set(
                 build_set(GameState::Settlement(SettlementState::Overview))
                     .with_system(settlement_ui::settlement_ui)
@@ -0,0 +1,105 @@
+use crate::{game_time::GameTimeAdvancedEvent, resources::Resource, settlement::Settlement};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ModifierKind {
+    Production,
+    Demand,
+    Max,
+    Price,
+}
+
+impl std::fmt::Display for ModifierKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ModifierKind::Production => "production",
+            ModifierKind::Demand => "demand",
+            ModifierKind::Max => "storage",
+            ModifierKind::Price => "prices",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// temporary change to the economy of a settlement, e.g. a failed harvest
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementModifier {
+    pub name: String,
+    pub kind: ModifierKind,
+    /// resource affected, all of them if left out
+    pub resource: Option<String>,
+    pub multiplier: f32,
+    /// seasons the modifier stays active
+    pub seasons: u32,
+}
+
+impl SettlementModifier {
+    fn applies(&self, kind: ModifierKind, resource: &str) -> bool {
+        self.kind == kind
+            && self
+                .resource
+                .as_ref()
+                .map_or(true, |affected| affected == resource)
+    }
+}
+
+impl std::fmt::Display for SettlementModifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} of {} x{:.1}, {} seasons left",
+            self.name,
+            self.kind,
+            self.resource.as_deref().unwrap_or("all goods"),
+            self.multiplier,
+            self.seasons
+        )
+    }
+}
+
+impl Settlement {
+    /// combined multiplier of all active modifiers of a kind for a resource
+    pub fn modifier(&self, kind: ModifierKind, resource: &str) -> f32 {
+        self.modifiers
+            .iter()
+            .filter(|modifier| modifier.applies(kind, resource))
+            .fold(1., |multiplier, modifier| multiplier * modifier.multiplier)
+    }
+
+    /// demand of the population for a resource, with modifiers applied
+    pub fn demand(&self, resource: &Resource) -> f32 {
+        resource.demand.value(&self.populations)
+            * self.modifier(ModifierKind::Demand, &resource.name)
+    }
+
+    pub fn add_modifier(&mut self, modifier: SettlementModifier) {
+        if modifier.seasons > 0 {
+            self.modifiers.push(modifier);
+        }
+    }
+
+    /// counts down the active modifiers after a season, dropping expired ones
+    pub fn modifiers_tick(&mut self) {
+        for modifier in self.modifiers.iter_mut() {
+            modifier.seasons -= 1;
+        }
+        self.modifiers.retain(|modifier| modifier.seasons > 0);
+    }
+}
+
+pub fn age_modifiers(
+    mut settlements: Query<&mut Settlement>,
+    mut events: EventReader<GameTimeAdvancedEvent>,
+) {
+    for _ in events.iter() {
+        for mut settlement in settlements.iter_mut() {
+            settlement.modifiers_tick();
+        }
+    }
+}
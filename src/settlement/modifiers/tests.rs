@@ -0,0 +1,66 @@
+use super::*;
+
+fn setup() -> (Settlement, Resource) {
+    let settlement = Settlement::test_city("Sinope", &["Farmer", "Farmer"], &[("Wine", 10)]);
+
+    let resource: Resource = serde_yaml::from_str(
+        "
+name: Wine
+base_price: 20
+demand:
+  Population: 5
+max:
+  Population: 20
+",
+    )
+    .unwrap();
+
+    (settlement, resource)
+}
+
+fn festival(seasons: u32) -> SettlementModifier {
+    SettlementModifier {
+        name: "Festival".to_owned(),
+        kind: ModifierKind::Demand,
+        resource: Some("Wine".to_owned()),
+        multiplier: 2.,
+        seasons,
+    }
+}
+
+#[test]
+fn modifiers_multiply_demand_and_prices() {
+    let (mut settlement, resource) = setup();
+    assert_eq!(settlement.demand(&resource), 10.);
+
+    settlement.add_modifier(festival(1));
+    assert_eq!(settlement.demand(&resource), 20.);
+    assert_eq!(settlement.prices(&resource).demand, 20);
+    assert_eq!(settlement.modifier(ModifierKind::Demand, "Grain"), 1.);
+
+    settlement.add_modifier(SettlementModifier {
+        name: "Blockade".to_owned(),
+        kind: ModifierKind::Price,
+        resource: None,
+        multiplier: 1.5,
+        seasons: 2,
+    });
+    assert_eq!(settlement.prices(&resource).base_price, 30);
+}
+
+#[test]
+fn modifiers_expire_after_their_seasons() {
+    let (mut settlement, _) = setup();
+    settlement.add_modifier(festival(1));
+    settlement.add_modifier(festival(3));
+    settlement.add_modifier(festival(0));
+    assert_eq!(settlement.modifiers.len(), 2);
+
+    settlement.modifiers_tick();
+    assert_eq!(settlement.modifiers.len(), 1);
+    assert_eq!(settlement.modifiers[0].seasons, 2);
+
+    settlement.modifiers_tick();
+    settlement.modifiers_tick();
+    assert!(settlement.modifiers.is_empty());
+}
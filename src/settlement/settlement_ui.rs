@@ -1,10 +1,15 @@
 use crate::{
     game_state::GameState,
     settlement::{
-        ui::{buildings_ui, population_info, production_ui, resource_info},
+        ui::{
+            buildings_ui, docked_ships_ui, modifiers_ui, population_info, production_ui,
+            resource_info, shortage_ui,
+        },
         Settlement,
     },
+    types::Player,
     ui::{create_window_with_mobile, CloseSettlementUIEvent, SelectedSettlement},
+    Settings,
 };
 use bevy::prelude::*;
 use bevy_egui::{
@@ -14,17 +19,21 @@ use bevy_egui::{
 
 pub fn settlement_ui(
     mut commands: Commands,
-    mut egui_context: ResMut<EguiContext>,
+    ui_resources: (Res<Windows>, ResMut<EguiContext>),
     selected_settlement: Option<Res<SelectedSettlement>>,
     settlements: Query<&Settlement>,
     mut events: EventWriter<CloseSettlementUIEvent>,
     mut game_state: ResMut<State<GameState>>,
-    windows: Res<Windows>,
+    world: (ResMut<Player>, Res<Settings>),
 ) {
+    let (windows, mut egui_context) = ui_resources;
+    let (mut player, settings) = world;
+
     if let Some(entity) = selected_settlement.as_ref() {
         let settlement = settlements
             .get(entity.0)
             .expect("Expected settlement to be selected");
+        let in_port = player.location == Some(entity.0);
 
         let mut open = true;
         create_window_with_mobile(
@@ -40,11 +49,17 @@ pub fn settlement_ui(
                         egui::ScrollArea::both().id_source("info").show(ui, |ui| {
                             buildings_ui(ui, settlement, &mut game_state, &mut commands);
                             ui.add_space(5.);
-                            population_info(ui, settlement);
+                            docked_ships_ui(ui, settlement, &mut player, in_port);
+                            ui.add_space(5.);
+                            population_info(ui, settlement, &settings);
                             ui.add_space(5.);
                             resource_info(ui, settlement);
                             ui.add_space(5.);
                             production_ui(ui, settlement);
+                            ui.add_space(5.);
+                            shortage_ui(ui, settlement);
+                            ui.add_space(5.);
+                            modifiers_ui(ui, settlement);
                         });
                     });
                 } else {
@@ -53,7 +68,8 @@ pub fn settlement_ui(
                             &mut columns[0],
                             |ui| {
                                 buildings_ui(ui, settlement, &mut game_state, &mut commands);
-                                population_info(ui, settlement);
+                                docked_ships_ui(ui, settlement, &mut player, in_port);
+                                population_info(ui, settlement, &settings);
                             },
                         );
 
@@ -63,6 +79,10 @@ pub fn settlement_ui(
                                 resource_info(ui, settlement);
                                 ui.add_space(5.);
                                 production_ui(ui, settlement);
+                                ui.add_space(5.);
+                                shortage_ui(ui, settlement);
+                                ui.add_space(5.);
+                                modifiers_ui(ui, settlement);
                             },
                         );
                     });
@@ -0,0 +1,39 @@
+use crate::{
+    game_time::{GameTime, GameTimeAdvancedEvent},
+    resources::Resource,
+    settlement::{PriceRecord, Settlement, TRACK_PRICE_TICKS},
+};
+use bevy::prelude::*;
+
+pub fn track_prices(
+    mut settlements: Query<&mut Settlement>,
+    mut events: EventReader<GameTimeAdvancedEvent>,
+    resources: Option<Res<Vec<Resource>>>,
+) {
+    if let Some(resources) = resources {
+        for event in events.iter() {
+            for mut settlement in settlements.iter_mut() {
+                settlement.track_price_tick(&event.time, &resources);
+            }
+        }
+    }
+}
+
+impl Settlement {
+    pub fn track_price_tick(&mut self, time: &GameTime, resources: &[Resource]) {
+        for resource in resources.iter() {
+            let prices = self.prices(resource);
+            let record = PriceRecord {
+                time: time.clone(),
+                buy: prices.buy_price(),
+                sell: prices.sell_price(),
+            };
+
+            let history = self.price_history.entry(resource.name.clone()).or_default();
+            history.push(record);
+            if history.len() > TRACK_PRICE_TICKS {
+                history.remove(0);
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{resources::Resource, settlement::Settlement, types::Player};
+use bevy_egui::egui::{self, RichText, Ui};
+
+/// resource and amount picked for trading several units at once
+#[derive(Default)]
+pub struct TradeQuantity {
+    pub resource: Option<String>,
+    pub amount: u32,
+}
+
+pub struct BulkTrade<'a> {
+    pub ui: &'a mut Ui,
+    pub resource: &'a Resource,
+    pub settlement: &'a mut Settlement,
+    pub player: &'a mut Player,
+    pub quantity: &'a mut TradeQuantity,
+}
+
+fn average(total: u32, amount: u32) -> f32 {
+    total as f32 / u32::max(amount, 1) as f32
+}
+
+impl<'a> BulkTrade<'a> {
+    pub fn render(&mut self) {
+        let Self {
+            ui,
+            resource,
+            settlement,
+            player,
+            quantity,
+        } = self;
+        let (ui, resource, settlement, player) =
+            (&mut **ui, *resource, &mut **settlement, &mut **player);
+
+        let prices = settlement.prices(resource);
+        let player_count = player.resource_count(&resource.name);
+        let settlement_count = *settlement.resources.get(&resource.name).unwrap_or(&0);
+        let space_left = player.space_left_for(resource);
+
+        ui.label(
+            RichText::new(format!("Trade {}", resource.name))
+                .text_style(crate::ui::panel_heading())
+                .strong(),
+        );
+
+        ui.horizontal(|ui| {
+            ui.label("Amount");
+            ui.add(
+                egui::DragValue::new(&mut quantity.amount)
+                    .clamp_range(0..=u32::max(player_count, settlement_count)),
+            );
+
+            if ui.button("max affordable").clicked() {
+                quantity.amount =
+                    prices.max_buy(player.silver, u32::min(settlement_count, space_left));
+            }
+            if ui.button("fill cargo").clicked() {
+                quantity.amount = u32::min(settlement_count, space_left);
+            }
+            if ui.button("sell all").clicked() {
+                quantity.amount = prices.max_sell(settlement.silver, player_count);
+            }
+        });
+
+        let amount = quantity.amount;
+
+        ui.horizontal(|ui| {
+            let cost = prices.buy_total(u32::min(amount, settlement_count));
+            let buy_button = egui::Button::new(format!(
+                "buy {} for {} (avg {:.1})",
+                amount,
+                cost,
+                average(cost, amount)
+            ));
+            let reason = if amount == 0 {
+                Some("Choose an amount first")
+            } else if amount > settlement_count {
+                Some("The settlement does not have that many")
+            } else if amount > space_left && resource.min_hull > 0 {
+                Some("Not enough space in ships with a hull sturdy enough")
+            } else if amount > space_left {
+                Some("Not enough space in your convoy")
+            } else if cost > player.silver {
+                Some("Not enough silver")
+            } else {
+                None
+            };
+
+            if ui
+                .add_enabled(reason.is_none(), buy_button)
+                .on_disabled_hover_text(reason.unwrap_or_default())
+                .clicked()
+            {
+                *settlement
+                    .resources
+                    .entry(resource.name.clone())
+                    .or_default() -= amount;
+                player.add_resource(resource, amount);
+                player.silver -= cost;
+                settlement.silver += cost;
+                quantity.amount = 0;
+                log::info!("buy {} {} for {}", amount, resource.name, cost);
+            }
+
+            let revenue = prices.sell_total(u32::min(amount, player_count));
+            let sell_button = egui::Button::new(format!(
+                "sell {} for {} (avg {:.1})",
+                amount,
+                revenue,
+                average(revenue, amount)
+            ));
+            let reason = if amount == 0 {
+                Some("Choose an amount first")
+            } else if amount > player_count {
+                Some("You do not have that many")
+            } else if revenue > settlement.silver {
+                Some("The settlement cannot pay that much")
+            } else {
+                None
+            };
+
+            if ui
+                .add_enabled(reason.is_none(), sell_button)
+                .on_disabled_hover_text(reason.unwrap_or_default())
+                .clicked()
+            {
+                *settlement
+                    .resources
+                    .entry(resource.name.clone())
+                    .or_default() += amount;
+                player.remove_resource(&resource.name, amount);
+                player.silver += revenue;
+                settlement.silver -= revenue;
+                quantity.amount = 0;
+                log::info!("sell {} {} for {}", amount, resource.name, revenue);
+            }
+        });
+    }
+}
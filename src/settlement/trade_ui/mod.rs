@@ -1,7 +1,7 @@
 use super::Settlement;
 use crate::{
     game_state::{GameState, SettlementState},
-    price_calculator::{AveragePrices, PriceCalculator},
+    price_calculator::AveragePrices,
     resources::Resource,
     types::Player,
     ui::{create_window, large_button, SelectedSettlement},
@@ -13,8 +13,12 @@ use bevy_egui::{
     EguiContext,
 };
 
+mod bulk_trade;
+mod price_chart;
 mod trade_row;
 
+use bulk_trade::BulkTrade;
+pub use bulk_trade::TradeQuantity;
 use trade_row::TradeRow;
 
 pub fn trade_ui(
@@ -24,9 +28,13 @@ pub fn trade_ui(
     mut player: ResMut<Player>,
     mut game_state: ResMut<State<GameState>>,
     windows: Res<Windows>,
-    trade_info: (Res<Vec<Resource>>, Res<AveragePrices>),
+    trade_info: (
+        Res<Vec<Resource>>,
+        Res<AveragePrices>,
+        ResMut<TradeQuantity>,
+    ),
 ) {
-    let (resources, average_prices) = trade_info;
+    let (resources, average_prices, mut quantity) = trade_info;
 
     if let Some(entity) = selected_settlement.as_ref() {
         let mut settlement = settlements
@@ -69,22 +77,21 @@ pub fn trade_ui(
                                         .strong(),
                                 );
                                 ui.label("");
+                                ui.label(
+                                    RichText::new("Trend")
+                                        .text_style(crate::ui::panel_heading())
+                                        .strong(),
+                                );
                                 ui.end_row();
                             }
 
                             for resource in resources.iter() {
-                                let demand =
-                                    resource.demand.value(&settlement.populations).ceil() as u32;
-
-                                let prices = PriceCalculator {
-                                    base_price: resource.base_price,
-                                    demand,
-                                    supply: *settlement.resources.get(&resource.name).unwrap_or(&0),
-                                };
+                                let prices = settlement.prices(resource);
+                                let selected = quantity.resource.as_ref() == Some(&resource.name);
 
-                                TradeRow {
+                                let picked = TradeRow {
                                     ui,
-                                    resource: resource.name.clone(),
+                                    resource,
                                     player: &mut player,
                                     settlement: &mut settlement,
                                     sell_price: prices.sell_price(),
@@ -93,13 +100,19 @@ pub fn trade_ui(
                                         .prices
                                         .get(&resource.name)
                                         .unwrap_or(&0.0),
+                                    selected,
                                 }
                                 .render();
                                 ui.end_row();
+
+                                if picked && !selected {
+                                    quantity.resource = Some(resource.name.clone());
+                                    quantity.amount = 0;
+                                }
                             }
 
                             {
-                                for _ in 0..6 {
+                                for _ in 0..7 {
                                     ui.separator();
                                 }
                                 ui.end_row();
@@ -116,6 +129,7 @@ pub fn trade_ui(
                                     ui.label(format!("{}", settlement.silver));
                                 });
                                 ui.label("");
+                                ui.label("");
                                 ui.end_row();
                             }
 
@@ -132,9 +146,26 @@ pub fn trade_ui(
                                 ui.label("");
                                 ui.label("");
                                 ui.label("");
+                                ui.label("");
                                 ui.end_row();
                             }
                         });
+
+                        let picked_resource = resources
+                            .iter()
+                            .find(|resource| quantity.resource.as_ref() == Some(&resource.name));
+
+                        if let Some(resource) = picked_resource {
+                            ui.add_space(10.);
+                            BulkTrade {
+                                ui,
+                                resource,
+                                settlement: &mut settlement,
+                                player: &mut player,
+                                quantity: &mut quantity,
+                            }
+                            .render();
+                        }
                     });
             },
         );
@@ -0,0 +1,64 @@
+use crate::settlement::{PriceRecord, TRACK_PRICE_TICKS};
+use bevy_egui::egui::{self, Color32, Response, Stroke, Ui};
+
+const CHART_WIDTH: f32 = 80.;
+const CHART_HEIGHT: f32 = 20.;
+const BUY_COLOR: Color32 = Color32::DARK_RED;
+const SELL_COLOR: Color32 = Color32::DARK_GREEN;
+
+/// sparkline of the buy and sell prices of the last seasons, newest on the right
+pub fn price_chart(ui: &mut Ui, history: &[PriceRecord]) -> Response {
+    let (response, painter) =
+        ui.allocate_painter(egui::vec2(CHART_WIDTH, CHART_HEIGHT), egui::Sense::hover());
+
+    if history.len() < 2 {
+        return response;
+    }
+
+    let rect = response.rect;
+    let max = history.iter().map(|r| r.buy.max(r.sell)).max().unwrap() as f32;
+    let min = history.iter().map(|r| r.buy.min(r.sell)).min().unwrap() as f32;
+    let range = (max - min).max(1.);
+    let step = rect.width() / (TRACK_PRICE_TICKS - 1) as f32;
+
+    let points = |price: fn(&PriceRecord) -> u32| -> Vec<egui::Pos2> {
+        history
+            .iter()
+            .enumerate()
+            .map(|(index, record)| {
+                let age = (history.len() - 1 - index) as f32;
+                egui::pos2(
+                    rect.right() - age * step,
+                    rect.bottom() - (price(record) as f32 - min) / range * rect.height(),
+                )
+            })
+            .collect()
+    };
+
+    painter.add(egui::Shape::line(
+        points(|r| r.buy),
+        Stroke::new(1., BUY_COLOR),
+    ));
+    painter.add(egui::Shape::line(
+        points(|r| r.sell),
+        Stroke::new(1., SELL_COLOR),
+    ));
+
+    response
+}
+
+pub fn price_chart_tooltip(history: &[PriceRecord]) -> String {
+    let mut lines = vec!["Buy / sell prices of the last seasons".to_owned()];
+
+    for record in history.iter().rev() {
+        lines.push(format!(
+            "Year {}, {}: {} / {}",
+            record.time.year,
+            record.time.season_name(),
+            record.buy,
+            record.sell
+        ));
+    }
+
+    lines.join("\n")
+}
@@ -1,14 +1,16 @@
-use crate::{settlement::Settlement, types::Player, ui::enabled_color};
+use super::price_chart::{price_chart, price_chart_tooltip};
+use crate::{resources::Resource, settlement::Settlement, types::Player, ui::enabled_color};
 use bevy_egui::egui::{self, Align, RichText, Ui};
 
 pub struct TradeRow<'a> {
     pub ui: &'a mut Ui,
-    pub resource: String,
+    pub resource: &'a Resource,
     pub settlement: &'a mut Settlement,
     pub player: &'a mut Player,
     pub sell_price: u32,
     pub buy_price: u32,
     pub average_price: f32,
+    pub selected: bool,
 }
 
 fn button(ui: &mut Ui, text: String, enabled: bool) -> egui::Response {
@@ -19,14 +21,21 @@ fn button(ui: &mut Ui, text: String, enabled: bool) -> egui::Response {
 }
 
 impl<'a> TradeRow<'a> {
-    pub fn render(&mut self) {
-        let player_count = *self.player.resources.get(&self.resource).unwrap_or(&0);
-        let settlement_count = *self.settlement.resources.get(&self.resource).unwrap_or(&0);
+    /// renders the row, returns whether the resource was picked for bulk trade
+    pub fn render(&mut self) -> bool {
+        let resource = self.resource;
+        let name = &resource.name;
+        let player_count = self.player.resource_count(name);
+        let settlement_count = *self.settlement.resources.get(name).unwrap_or(&0);
 
-        self.ui.label(
-            RichText::new(&self.resource)
-                .color(enabled_color(player_count > 0 || settlement_count > 0)),
-        );
+        let picked = self
+            .ui
+            .selectable_label(
+                self.selected,
+                RichText::new(name).color(enabled_color(player_count > 0 || settlement_count > 0)),
+            )
+            .on_hover_text("Trade several units at once")
+            .clicked();
 
         {
             self.ui
@@ -40,42 +49,26 @@ impl<'a> TradeRow<'a> {
             let text = format!("sell ({})", self.sell_price);
 
             if button(self.ui, text, enabled).clicked() && enabled {
-                *self
-                    .settlement
-                    .resources
-                    .entry(self.resource.clone())
-                    .or_default() += 1;
-                *self
-                    .player
-                    .resources
-                    .entry(self.resource.clone())
-                    .or_default() -= 1;
+                *self.settlement.resources.entry(name.clone()).or_default() += 1;
+                self.player.remove_resource(name, 1);
                 self.player.silver += self.sell_price;
                 self.settlement.silver -= self.sell_price;
-                log::info!("sell {} for {}", self.resource, self.sell_price);
+                log::info!("sell {} for {}", name, self.sell_price);
             }
         }
 
         {
             let enabled = settlement_count > 0
                 && self.player.silver >= self.buy_price
-                && self.player.resource_space_left() > 0;
+                && self.player.space_left_for(resource) > 0;
             let text = format!("buy ({})", self.buy_price);
 
             if button(self.ui, text, enabled).clicked() && enabled {
-                *self
-                    .settlement
-                    .resources
-                    .entry(self.resource.clone())
-                    .or_default() -= 1;
-                *self
-                    .player
-                    .resources
-                    .entry(self.resource.clone())
-                    .or_default() += 1;
+                *self.settlement.resources.entry(name.clone()).or_default() -= 1;
+                self.player.add_resource(resource, 1);
                 self.player.silver -= self.buy_price;
                 self.settlement.silver += self.buy_price;
-                log::info!("buy {} for {}", self.resource, self.buy_price);
+                log::info!("buy {} for {}", name, self.buy_price);
             }
         }
 
@@ -106,5 +99,18 @@ impl<'a> TradeRow<'a> {
                     ui.label(wording);
                 });
         }
+
+        {
+            let history = self
+                .settlement
+                .price_history
+                .get(name)
+                .map(|history| history.as_slice())
+                .unwrap_or_default();
+
+            price_chart(self.ui, history).on_hover_text(price_chart_tooltip(history));
+        }
+
+        picked
     }
 }
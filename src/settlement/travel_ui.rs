@@ -3,28 +3,82 @@ use super::{
     Settlement, VisitSettlementEvent,
 };
 use crate::{
-    player::PlayerTravelEvent,
+    game_events::{travel_risk, ConditionContext, GameEvent, GameEventsState},
+    game_time::GameTime,
+    map::navigation::SeaRoutes,
+    player::{winter_share, PlayerTravelEvent},
+    types::{Hold, Player},
     ui::{large_button, CloseSettlementUIEvent, SelectedSettlement},
+    Settings,
 };
 use bevy::prelude::*;
 use bevy_egui::{
     egui::{self, Align2},
     EguiContext,
 };
+use std::collections::{HashMap, HashSet};
 
+#[allow(clippy::type_complexity)]
 pub fn travel_ui(
     mut egui_context: ResMut<EguiContext>,
     selected_settlement: Option<Res<SelectedSettlement>>,
-    settlements: Query<&Settlement>,
+    settlements: Query<(Entity, &Settlement)>,
     mut events: EventWriter<CloseSettlementUIEvent>,
     mut handle_travel: EventWriter<PlayerTravelEvent>,
     mut visit_events: EventWriter<VisitSettlementEvent>,
+    voyage: (
+        ResMut<Player>,
+        Option<Res<SeaRoutes>>,
+        Option<Res<HashMap<String, GameEvent>>>,
+        Res<GameTime>,
+        Option<Res<Settings>>,
+        Local<HashSet<usize>>,
+        Res<GameEventsState>,
+    ),
 ) {
+    let (mut player, sea_routes, game_events, game_time, settings, mut staying, events_state) =
+        voyage;
+
     if let Some(entity) = selected_settlement.as_ref() {
-        let settlement = settlements
+        let (_, settlement) = settlements
             .get(entity.0)
             .expect("Expected settlement to be selected");
 
+        let port = player
+            .location
+            .and_then(|location| settlements.get(location).ok())
+            .map(|(_, port)| port);
+        let route = port
+            .zip(sea_routes.as_ref())
+            .and_then(|(port, sea_routes)| sea_routes.get(&port.name, &settlement.name));
+
+        // ships left in port, at least one has to sail
+        let convoy_size = player.convoy.len();
+        staying.retain(|index| *index < convoy_size);
+        if staying.len() == convoy_size {
+            staying.clear();
+        }
+        let sailing: Vec<usize> = (0..convoy_size)
+            .filter(|index| !staying.contains(index))
+            .collect();
+
+        let seasons = route.map(|route| route.seasons(player.travel_speed_with(&sailing)));
+        let convoy_seasons = route.map(|route| route.seasons(player.travel_speed()));
+        let risk = seasons
+            .zip(game_events.as_ref())
+            .map(|(seasons, game_events)| {
+                let context =
+                    ConditionContext::new(&player, &game_time, &events_state, settlements.iter());
+                travel_risk(game_events, seasons, &context)
+            });
+        let wear = route
+            .zip(seasons)
+            .zip(settings.as_ref())
+            .map(|((route, seasons), settings)| {
+                let winter = winter_share(&game_time, seasons);
+                player.voyage_wear(&sailing, route.length, winter, &settings.wear)
+            });
+
         egui::Window::new(format!("Travel to {}", settlement.name))
             .resizable(false)
             .collapsible(false)
@@ -38,16 +92,72 @@ pub fn travel_ui(
                 }
                 ui.add_space(10.);
 
+                match seasons {
+                    Some(1) => ui.label("The voyage takes one season."),
+                    Some(seasons) => ui.label(format!("The voyage takes {} seasons.", seasons)),
+                    None => ui.label("There is no known sea route to this settlement."),
+                };
+                if port.is_some() && convoy_size > 1 {
+                    ui.add_space(5.);
+                    ui.label("Ships sailing along, the slowest one sets the pace:");
+                    for index in 0..convoy_size {
+                        let mut sails = !staying.contains(&index);
+                        let last = sails && sailing.len() == 1;
+                        let label = format!(
+                            "{} (speed {:.0})",
+                            player.hold_name(Hold::Ship(index)),
+                            player.convoy[index].speed()
+                        );
+
+                        if ui
+                            .add_enabled(!last, egui::Checkbox::new(&mut sails, label))
+                            .on_disabled_hover_text("At least one ship has to sail")
+                            .changed()
+                        {
+                            if sails {
+                                staying.remove(&index);
+                            } else {
+                                staying.insert(index);
+                            }
+                        }
+                    }
+
+                    if let Some(convoy_seasons) = convoy_seasons.filter(|s| Some(*s) != seasons) {
+                        ui.label(format!(
+                            "With the whole convoy the voyage takes {} seasons.",
+                            convoy_seasons
+                        ));
+                    }
+                }
+                if let Some(risk) = risk {
+                    ui.label(format!("Risk of mishaps at sea: {:.0}%", risk * 100.));
+                }
+                if let Some(wear) = wear.filter(|wear| *wear > 0) {
+                    ui.label(format!("Expected wear: {} damage to your ships", wear));
+                }
+                ui.add_space(10.);
+
                 ui.columns(2, |columns| {
                     if large_button(&mut columns[0], 60., "Abort").clicked() {
                         events.send(CloseSettlementUIEvent);
                     }
 
-                    if large_button(&mut columns[1], 80., "Travel").clicked() {
+                    let travel = columns[1]
+                        .add_enabled_ui(seasons.is_some(), |ui| large_button(ui, 80., "Travel"))
+                        .inner;
+
+                    if travel.clicked() {
+                        if let Some(port) = port {
+                            let staying: Vec<usize> = staying.drain().collect();
+                            player.dock_ships(&staying, &port.name);
+                        }
                         handle_travel.send(PlayerTravelEvent::new(
                             entity.0,
                             settlement.position.x,
                             settlement.position.y,
+                            seasons.unwrap_or(1),
+                            route.map(|route| route.tiles.clone()).unwrap_or_default(),
+                            route.map(|route| route.length).unwrap_or_default(),
                         ));
                         visit_events.send(VisitSettlementEvent {
                             settlement: entity.0,
@@ -2,11 +2,12 @@ use crate::{
     building::BuildingType,
     game_state::{GameState, SettlementState},
     settlement::Settlement,
+    types::Player,
     ui::{large_button, SelectedBuilding},
-    COIN_NAME,
+    Settings, COIN_NAME,
 };
 use bevy::prelude::*;
-use bevy_egui::egui::Ui;
+use bevy_egui::egui::{self, Ui};
 
 pub fn production_ui(ui: &mut Ui, settlement: &Settlement) {
     ui.heading("Local production");
@@ -17,10 +18,40 @@ pub fn production_ui(ui: &mut Ui, settlement: &Settlement) {
     }
 }
 
-pub fn population_info(ui: &mut Ui, settlement: &Settlement) {
+pub fn shortage_ui(ui: &mut Ui, settlement: &Settlement) {
+    let shortages = settlement.shortages();
+    if shortages.is_empty() {
+        return;
+    }
+
+    ui.heading("Shortages");
+    ui.add_space(5.);
+
+    for (resource, amount) in shortages {
+        ui.label(format!(" - {} ({} missing)", resource, amount));
+    }
+}
+
+pub fn modifiers_ui(ui: &mut Ui, settlement: &Settlement) {
+    if settlement.modifiers.is_empty() {
+        return;
+    }
+
+    ui.heading("Current events");
+    ui.add_space(5.);
+
+    for modifier in settlement.modifiers.iter() {
+        ui.label(format!(" - {}", modifier));
+    }
+}
+
+pub fn population_info(ui: &mut Ui, settlement: &Settlement, settings: &Settings) {
     ui.heading(format!("Population ({})", settlement.populations.len()));
     ui.add_space(5.);
 
+    ui.label(settings.demographics.outlook(settlement.prosperity()));
+    ui.add_space(5.);
+
     for population in settlement.populations.iter() {
         ui.label(format!(" - {}", population));
     }
@@ -44,6 +75,46 @@ pub fn resource_info(ui: &mut Ui, settlement: &Settlement) {
     ui.label(format!(" - {}: {}", COIN_NAME, settlement.silver));
 }
 
+pub fn docked_ships_ui(ui: &mut Ui, settlement: &Settlement, player: &mut Player, in_port: bool) {
+    let docked: Vec<usize> = player
+        .docked
+        .iter()
+        .enumerate()
+        .filter(|(_, docked)| docked.settlement == settlement.name)
+        .map(|(index, _)| index)
+        .collect();
+    if docked.is_empty() {
+        return;
+    }
+
+    ui.heading("Your docked ships");
+    ui.add_space(5.);
+
+    let mut undocked = None;
+    for index in docked {
+        let ship = &player.docked[index].ship;
+        ui.horizontal(|ui| {
+            ui.label(format!(
+                " - {}, cargo {}/{}",
+                ship,
+                ship.cargo_used(),
+                ship.resource_space()
+            ));
+            if ui
+                .add_enabled(in_port, egui::Button::new("Join convoy"))
+                .on_disabled_hover_text("Your convoy is not in this port")
+                .clicked()
+            {
+                undocked = Some(index);
+            }
+        });
+    }
+
+    if let Some(index) = undocked {
+        player.undock_ship(index);
+    }
+}
+
 pub fn list_buildings_ui(ui: &mut Ui, settlement: &Settlement) {
     if settlement.buildings.is_empty() {
         return;
@@ -59,7 +130,7 @@ pub fn list_buildings_ui(ui: &mut Ui, settlement: &Settlement) {
                 BuildingType::Temple(temple) => {
                     format!("Temple of {}", temple.deity)
                 }
-                BuildingType::Shipyard => {
+                BuildingType::Shipyard(_) => {
                     "Shipyard".to_owned()
                 }
             }
@@ -95,7 +166,7 @@ pub fn buildings_ui(
                         .unwrap();
                 }
             }
-            BuildingType::Shipyard => {
+            BuildingType::Shipyard(_) => {
                 if large_button(ui, 100., "Shipyard").clicked() {
                     if let Some(entity) = building.entity {
                         commands.insert_resource(SelectedBuilding(entity))
@@ -0,0 +1,119 @@
+use crate::{
+    economy::EconomyPlugin,
+    game_rng::GameRng,
+    game_time::{GameTime, GameTimeAdvancedEvent},
+    population::Population,
+    price_calculator::AveragePrices,
+    resources::Resource,
+    settlement::Settlement,
+    Settings,
+};
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+};
+
+const SEASONS_PER_YEAR: u32 = 6;
+
+fn load_asset<T: DeserializeOwned>(path: &str) -> T {
+    let file = File::open(path).unwrap();
+    let reader = BufReader::new(file);
+    serde_yaml::from_reader(reader).unwrap()
+}
+
+fn write_season(
+    output: &mut impl Write,
+    world: &mut World,
+    resources: &[Resource],
+) -> std::io::Result<()> {
+    let time = world.get_resource::<GameTime>().unwrap().clone();
+    let average_prices = world.get_resource::<AveragePrices>().unwrap().clone();
+
+    let mut settlements = world.query::<&Settlement>();
+    for settlement in settlements.iter(world) {
+        for resource in resources.iter() {
+            let prices = settlement.prices(resource);
+
+            writeln!(
+                output,
+                "{},{},{},{},{},{},{},{},{:.2}",
+                time.year,
+                time.season,
+                settlement.name,
+                resource.name,
+                prices.supply,
+                settlement.last_shortage(&resource.name),
+                settlement.silver,
+                prices.sell_price(),
+                average_prices.prices.get(&resource.name).unwrap_or(&0.0)
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs the economy systems headless for the given amount of years after the
+/// usual warm-up and writes the state of every season as CSV.
+pub fn simulate(years: u32, output: &str, seed: Option<u64>) {
+    let mut resources: Vec<Resource> = load_asset("assets/game.resources");
+    let mut populations: Vec<Population> = load_asset("assets/game.populations");
+    let settlements: Vec<Settlement> = load_asset("assets/game.settlements");
+    let settings: Settings = load_asset("assets/game.settings");
+
+    resources.sort_by(|a, b| a.name.partial_cmp(&b.name).unwrap());
+    populations.sort_by(|a, b| a.name.partial_cmp(&b.name).unwrap());
+
+    let rng = match seed.or(settings.seed) {
+        Some(seed) => GameRng::new(seed),
+        None => GameRng::default(),
+    };
+    println!("Simulating {} years with seed {}", years, rng.seed());
+
+    let mut app = App::new();
+    app.add_event::<GameTimeAdvancedEvent>()
+        .init_resource::<GameTime>()
+        .insert_resource(rng)
+        .insert_resource(resources.clone())
+        .insert_resource(populations)
+        .insert_resource(settings)
+        .add_plugin(EconomyPlugin);
+
+    for mut settlement in settlements.into_iter() {
+        settlement.populations.sort();
+        app.world.spawn().insert(settlement);
+    }
+
+    let file = File::create(output).unwrap();
+    let mut output_writer = BufWriter::new(file);
+    writeln!(
+        output_writer,
+        "year,season,settlement,resource,supply,shortage,silver,price,average_price"
+    )
+    .unwrap();
+
+    let mut recorded_seasons = 0;
+    while recorded_seasons < years * SEASONS_PER_YEAR {
+        let time = {
+            let mut game_time = app.world.get_resource_mut::<GameTime>().unwrap();
+            game_time.advance();
+            game_time.clone()
+        };
+
+        app.world
+            .get_resource_mut::<Events<GameTimeAdvancedEvent>>()
+            .unwrap()
+            .send(GameTimeAdvancedEvent { time: time.clone() });
+        app.update();
+
+        if time.is_initialized() {
+            write_season(&mut output_writer, &mut app.world, &resources).unwrap();
+            recorded_seasons += 1;
+        }
+    }
+
+    output_writer.flush().unwrap();
+    println!("Wrote {} seasons to {}", recorded_seasons, output);
+}
@@ -4,6 +4,6 @@ mod seasonal_amount;
 mod ship;
 
 pub use calculated_population_value::CalculatedPopulationValue;
-pub use player::Player;
+pub use player::{Hold, Insurance, InsuranceTerm, Player, Upkeep};
 pub use seasonal_amount::SeasonalAmount;
-pub use ship::{Ship, ShipSize};
+pub use ship::{Ship, ShipClass};
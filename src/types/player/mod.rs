@@ -0,0 +1,348 @@
+use crate::{resources::Resource, types::Ship};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests;
+
+const BASE_RESOURCE_SPACE: u32 = 5;
+/// tiles sailed per season without any ships
+const TRAVEL_SPEED: f32 = 20.;
+/// share of the speed left to a convoy without any crew
+const UNDERMANNED_SPEED: f32 = 0.5;
+
+/// part of the convoy cargo can be stowed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hold {
+    /// space the convoy has regardless of its ships
+    Base,
+    /// hold of the ship at this index of the convoy
+    Ship(usize),
+}
+
+/// what the convoy cost in the last season
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct Upkeep {
+    pub wages: u32,
+    pub provisions: u32,
+    pub provisions_bought: u32,
+    pub hired: u32,
+    pub deserted: u32,
+}
+
+/// ship left behind in a port
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockedShip {
+    pub settlement: String,
+    pub ship: Ship,
+}
+
+/// cover for the cargo bought at a temple
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Insurance {
+    pub term: InsuranceTerm,
+    /// value of cargo still covered
+    pub value: u32,
+    /// whether the current voyage is covered
+    pub covering: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InsuranceTerm {
+    /// the next voyage
+    Voyage,
+    /// all voyages starting in the season
+    Season { year: i16, season: i8 },
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct Player {
+    #[serde(skip)]
+    pub position: Vec2,
+    #[serde(skip)]
+    pub location: Option<Entity>,
+    pub silver: u32,
+    /// cargo in the base hold, see `cargo` for everything the convoy carries
+    pub resources: HashMap<String, u32>,
+    #[serde(skip)]
+    pub location_marker: Option<Entity>,
+    #[serde(skip)]
+    pub location_marker_texture_atlas_handle: Option<Handle<TextureAtlas>>,
+    #[serde(skip)]
+    pub location_marker_need_update: bool,
+    pub convoy: Vec<Ship>,
+    #[serde(default)]
+    pub upkeep: Upkeep,
+    #[serde(default)]
+    pub insurance: Option<Insurance>,
+    #[serde(default)]
+    pub docked: Vec<DockedShip>,
+}
+
+impl Player {
+    pub fn update_position(&mut self, position: Vec2, location: Option<Entity>) {
+        self.position = position;
+        self.location = location;
+        self.location_marker_need_update = true;
+    }
+
+    /// the slowest ship sets the pace, undermanned convoys sail slower
+    pub fn travel_speed(&self) -> f32 {
+        convoy_speed(&self.convoy.iter().collect::<Vec<&Ship>>())
+    }
+
+    /// speed of the convoy when only the ships at the given indices sail
+    pub fn travel_speed_with(&self, sailing: &[usize]) -> f32 {
+        convoy_speed(
+            &sailing
+                .iter()
+                .filter_map(|index| self.convoy.get(*index))
+                .collect::<Vec<&Ship>>(),
+        )
+    }
+
+    /// share of the needed crew serving on the convoy
+    pub fn manned(&self) -> f32 {
+        manned(&self.convoy.iter().collect::<Vec<&Ship>>())
+    }
+
+    /// leaves the ships at the given indices in port
+    pub fn dock_ships(&mut self, indices: &[usize], settlement: &str) {
+        let mut indices = indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+
+        for index in indices.into_iter().rev() {
+            if index < self.convoy.len() {
+                let ship = self.convoy.remove(index);
+                self.docked.push(DockedShip {
+                    settlement: settlement.to_owned(),
+                    ship,
+                });
+            }
+        }
+    }
+
+    /// takes a docked ship back into the convoy
+    pub fn undock_ship(&mut self, index: usize) {
+        let docked = self.docked.remove(index);
+        self.convoy.push(docked.ship);
+    }
+
+    pub fn crew(&self) -> u32 {
+        self.convoy.iter().map(|ship| ship.crew).sum()
+    }
+
+    pub fn crew_needed(&self) -> u32 {
+        self.convoy.iter().map(|ship| ship.class.crew).sum()
+    }
+
+    pub fn resource_space_total(&self) -> u32 {
+        BASE_RESOURCE_SPACE
+            + self
+                .convoy
+                .iter()
+                .fold(0, |acc, transport| acc + transport.resource_space())
+    }
+
+    pub fn resource_space_left(&self) -> u32 {
+        self.resource_space_total() - self.resource_space_used()
+    }
+
+    pub fn resource_space_used(&self) -> u32 {
+        self.holds()
+            .into_iter()
+            .fold(0, |acc, hold| acc + self.hold_used(hold))
+    }
+
+    pub fn holds(&self) -> Vec<Hold> {
+        std::iter::once(Hold::Base)
+            .chain((0..self.convoy.len()).map(Hold::Ship))
+            .collect()
+    }
+
+    pub fn hold_name(&self, hold: Hold) -> String {
+        match hold {
+            Hold::Base => "Base hold".to_owned(),
+            Hold::Ship(index) => format!("{} #{}", self.convoy[index], index + 1),
+        }
+    }
+
+    pub fn hold(&self, hold: Hold) -> &HashMap<String, u32> {
+        match hold {
+            Hold::Base => &self.resources,
+            Hold::Ship(index) => &self.convoy[index].cargo,
+        }
+    }
+
+    fn hold_mut(&mut self, hold: Hold) -> &mut HashMap<String, u32> {
+        match hold {
+            Hold::Base => &mut self.resources,
+            Hold::Ship(index) => &mut self.convoy[index].cargo,
+        }
+    }
+
+    pub fn hold_capacity(&self, hold: Hold) -> u32 {
+        match hold {
+            Hold::Base => BASE_RESOURCE_SPACE,
+            Hold::Ship(index) => self.convoy[index].resource_space(),
+        }
+    }
+
+    pub fn hold_used(&self, hold: Hold) -> u32 {
+        self.hold(hold).values().sum()
+    }
+
+    pub fn hold_space_left(&self, hold: Hold) -> u32 {
+        self.hold_capacity(hold)
+            .saturating_sub(self.hold_used(hold))
+    }
+
+    /// some goods need a sturdy hull, the base hold has none
+    pub fn can_stow(&self, hold: Hold, resource: &Resource) -> bool {
+        let hull = match hold {
+            Hold::Base => 0,
+            Hold::Ship(index) => self.convoy[index].class.hull,
+        };
+        hull >= resource.min_hull
+    }
+
+    /// amount of a resource carried in all holds
+    pub fn resource_count(&self, resource: &str) -> u32 {
+        self.holds()
+            .into_iter()
+            .map(|hold| *self.hold(hold).get(resource).unwrap_or(&0))
+            .sum()
+    }
+
+    /// everything the convoy carries
+    pub fn cargo(&self) -> HashMap<String, u32> {
+        let mut cargo = HashMap::new();
+        for hold in self.holds() {
+            for (resource, amount) in self.hold(hold) {
+                *cargo.entry(resource.clone()).or_default() += amount;
+            }
+        }
+        cargo
+    }
+
+    /// space left in holds that can carry the resource
+    pub fn space_left_for(&self, resource: &Resource) -> u32 {
+        self.holds()
+            .into_iter()
+            .filter(|hold| self.can_stow(*hold, resource))
+            .map(|hold| self.hold_space_left(hold))
+            .sum()
+    }
+
+    /// stows up to `amount` in the first holds with space, returns the amount stowed
+    pub fn add_resource(&mut self, resource: &Resource, amount: u32) -> u32 {
+        let mut stowed = 0;
+        for hold in self.holds() {
+            if stowed == amount {
+                break;
+            }
+            if !self.can_stow(hold, resource) {
+                continue;
+            }
+
+            let added = u32::min(self.hold_space_left(hold), amount - stowed);
+            if added > 0 {
+                *self
+                    .hold_mut(hold)
+                    .entry(resource.name.clone())
+                    .or_default() += added;
+                stowed += added;
+            }
+        }
+        stowed
+    }
+
+    /// takes up to `amount` out of the holds, returns the amount taken
+    pub fn remove_resource(&mut self, resource: &str, amount: u32) -> u32 {
+        let mut removed = 0;
+        for hold in self.holds() {
+            if removed == amount {
+                break;
+            }
+            removed += take(self.hold_mut(hold), resource, amount - removed);
+        }
+        removed
+    }
+
+    /// moves up to `amount` between holds, returns the amount moved
+    pub fn move_cargo(&mut self, resource: &Resource, from: Hold, to: Hold, amount: u32) -> u32 {
+        if from == to || !self.can_stow(to, resource) {
+            return 0;
+        }
+
+        let amount = u32::min(amount, self.hold_space_left(to));
+        let moved = take(self.hold_mut(from), &resource.name, amount);
+        if moved > 0 {
+            *self.hold_mut(to).entry(resource.name.clone()).or_default() += moved;
+        }
+        moved
+    }
+
+    /// whether the cargo of the ship fits in the rest of the convoy
+    pub fn can_remove_ship(&self, index: usize, resources: &[Resource]) -> bool {
+        let mut rest = self.clone();
+        let ship = rest.convoy.remove(index);
+
+        ship.cargo.iter().all(|(name, amount)| {
+            resources
+                .iter()
+                .find(|resource| resource.name == *name)
+                .map(|resource| rest.add_resource(resource, *amount) == *amount)
+                .unwrap_or(false)
+        })
+    }
+
+    /// Takes the ship out of the convoy and stows its cargo in the other holds.
+    /// Refuses when the cargo does not fit.
+    pub fn remove_ship(&mut self, index: usize, resources: &[Resource]) -> Option<Ship> {
+        if !self.can_remove_ship(index, resources) {
+            return None;
+        }
+
+        let mut ship = self.convoy.remove(index);
+        for (name, amount) in std::mem::take(&mut ship.cargo) {
+            if let Some(resource) = resources.iter().find(|resource| resource.name == name) {
+                self.add_resource(resource, amount);
+            }
+        }
+        Some(ship)
+    }
+}
+
+fn manned(ships: &[&Ship]) -> f32 {
+    let crew: u32 = ships.iter().map(|ship| ship.crew).sum();
+    let crew_needed: u32 = ships.iter().map(|ship| ship.class.crew).sum();
+    if crew_needed == 0 {
+        1.
+    } else {
+        f32::min(crew as f32 / crew_needed as f32, 1.)
+    }
+}
+
+fn convoy_speed(ships: &[&Ship]) -> f32 {
+    let slowest = ships
+        .iter()
+        .map(|ship| ship.speed())
+        .reduce(f32::min)
+        .unwrap_or(TRAVEL_SPEED);
+
+    slowest * (UNDERMANNED_SPEED + (1. - UNDERMANNED_SPEED) * manned(ships))
+}
+
+fn take(hold: &mut HashMap<String, u32>, resource: &str, amount: u32) -> u32 {
+    let stored = hold.get(resource).copied().unwrap_or_default();
+    let taken = u32::min(stored, amount);
+    if taken == stored {
+        hold.remove(resource);
+    } else if let Some(stored) = hold.get_mut(resource) {
+        *stored -= taken;
+    }
+    taken
+}
@@ -0,0 +1,130 @@
+use super::*;
+use crate::types::ShipClass;
+
+fn setup() -> (Player, Resource, Resource) {
+    let player = Player {
+        convoy: vec![
+            Ship::new(&ShipClass::test_class("Small Ship", 20)),
+            Ship::new(&ShipClass::test_class("Medium Ship", 50)),
+        ],
+        ..default()
+    };
+
+    let mut resources: Vec<Resource> = serde_yaml::from_str(
+        "
+- name: Grain
+  base_price: 10
+  demand:
+    Population: 10
+  max:
+    Population: 100
+- name: Livestock
+  base_price: 30
+  min_hull: 50
+  demand:
+    Population: 1
+  max:
+    Population: 10
+",
+    )
+    .unwrap();
+    let livestock = resources.pop().unwrap();
+    let grain = resources.pop().unwrap();
+
+    (player, grain, livestock)
+}
+
+#[test]
+fn fills_holds_in_order() {
+    let (mut player, grain, _) = setup();
+
+    assert_eq!(player.add_resource(&grain, 30), 30);
+    assert_eq!(player.hold_used(Hold::Base), 5);
+    assert_eq!(player.hold_used(Hold::Ship(0)), 20);
+    assert_eq!(player.hold_used(Hold::Ship(1)), 5);
+    assert_eq!(player.resource_count("Grain"), 30);
+
+    assert_eq!(player.add_resource(&grain, 50), 45);
+    assert_eq!(player.resource_space_left(), 0);
+}
+
+#[test]
+fn livestock_needs_a_sturdy_hull() {
+    let (mut player, _, livestock) = setup();
+
+    assert!(!player.can_stow(Hold::Base, &livestock));
+    assert!(!player.can_stow(Hold::Ship(0), &livestock));
+    assert_eq!(player.space_left_for(&livestock), 50);
+
+    assert_eq!(player.add_resource(&livestock, 60), 50);
+    assert_eq!(player.hold_used(Hold::Ship(1)), 50);
+    assert_eq!(player.space_left_for(&livestock), 0);
+    assert_eq!(
+        player.move_cargo(&livestock, Hold::Ship(1), Hold::Ship(0), 10),
+        0
+    );
+}
+
+#[test]
+fn removes_from_base_hold_first() {
+    let (mut player, grain, _) = setup();
+    player.add_resource(&grain, 10);
+
+    assert_eq!(player.remove_resource("Grain", 7), 7);
+    assert_eq!(player.hold(Hold::Base).get("Grain"), None);
+    assert_eq!(player.hold(Hold::Ship(0)).get("Grain"), Some(&3));
+    assert_eq!(player.remove_resource("Grain", 7), 3);
+}
+
+#[test]
+fn moves_cargo_into_free_space() {
+    let (mut player, grain, _) = setup();
+    player.add_resource(&grain, 25);
+
+    assert_eq!(player.move_cargo(&grain, Hold::Ship(0), Hold::Base, 10), 0);
+    assert_eq!(
+        player.move_cargo(&grain, Hold::Ship(0), Hold::Ship(1), 30),
+        20
+    );
+    assert_eq!(player.hold(Hold::Ship(0)).get("Grain"), None);
+    assert_eq!(player.hold_used(Hold::Ship(1)), 20);
+}
+
+#[test]
+fn restows_cargo_of_removed_ship() {
+    let (mut player, grain, livestock) = setup();
+    player.add_resource(&grain, 30);
+    player.add_resource(&livestock, 10);
+    let resources = vec![grain, livestock];
+
+    // the livestock has no other ship to go to
+    assert!(!player.can_remove_ship(1, &resources));
+    assert!(player.remove_ship(1, &resources).is_none());
+
+    let ship = player.remove_ship(0, &resources).unwrap();
+    assert!(ship.cargo.is_empty());
+    assert_eq!(player.resource_count("Grain"), 30);
+    assert_eq!(player.hold_used(Hold::Ship(0)), 35);
+}
+
+#[test]
+fn slowest_ship_sets_the_pace() {
+    let (mut player, _, _) = setup();
+    player.convoy[1].class.speed = 15.;
+
+    assert_eq!(player.travel_speed(), 15.);
+    assert_eq!(player.travel_speed_with(&[0]), 20.);
+
+    // a badly damaged ship slows the convoy down
+    player.convoy[0].damage = 10;
+    assert!((player.travel_speed_with(&[0]) - 17.).abs() < 0.001);
+
+    player.dock_ships(&[1], "Olbia");
+    assert_eq!(player.convoy.len(), 1);
+    assert_eq!(player.docked[0].settlement, "Olbia");
+    assert!((player.travel_speed() - 17.).abs() < 0.001);
+
+    player.undock_ship(0);
+    assert_eq!(player.convoy.len(), 2);
+    assert!(player.docked.is_empty());
+}
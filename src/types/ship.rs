@@ -1,29 +1,79 @@
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum ShipSize {
-    Small,
-    Medium,
-    Large,
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// share of the price of a new ship a shipyard pays for one in perfect condition
+const SALE_SHARE: f32 = 0.5;
+/// share of its speed left to a ship about to sink
+const DAMAGED_SPEED: f32 = 0.7;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ShipClass {
+    pub name: String,
+    /// units of resources the ship can carry
+    pub capacity: u32,
+    /// damage the ship can take before it sinks
+    pub hull: u32,
+    pub price: u32,
+    /// seasons it takes a shipyard to build the ship
+    pub build_time: u32,
+    /// tiles the ship sails per season
+    pub speed: f32,
+    /// sailors needed to man the ship
+    pub crew: u32,
+    pub image: String,
+    /// resources a shipyard needs to build the ship
+    #[serde(default)]
+    pub materials: HashMap<String, u32>,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[cfg(test)]
+impl ShipClass {
+    /// ship class for tests, hull, price and crew grow with the capacity
+    pub fn test_class(name: &str, capacity: u32) -> Self {
+        Self {
+            name: name.to_owned(),
+            capacity,
+            hull: capacity,
+            price: capacity * 100,
+            build_time: 3,
+            speed: 20.,
+            crew: capacity / 5,
+            image: "images/ship_small.png".to_owned(),
+            materials: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Ship {
     pub damage: u32,
-    pub size: ShipSize,
+    pub class: ShipClass,
+    /// sailors currently serving on the ship
+    pub crew: u32,
+    /// goods stowed in this ship, lost with it
+    #[serde(default)]
+    pub cargo: HashMap<String, u32>,
+    /// seasons the ship is in service
+    #[serde(default)]
+    pub age: u32,
 }
 
 impl std::fmt::Display for Ship {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self.size {
-            ShipSize::Small => write!(f, "Small Ship"),
-            ShipSize::Medium => write!(f, "Medium Ship"),
-            ShipSize::Large => write!(f, "Large Ship"),
-        }
+        write!(f, "{}", self.class.name)
     }
 }
 
 impl Ship {
-    pub fn new(size: ShipSize) -> Self {
-        Self { size, damage: 0 }
+    pub fn new(class: &ShipClass) -> Self {
+        Self {
+            class: class.clone(),
+            damage: 0,
+            crew: class.crew,
+            cargo: HashMap::new(),
+            age: 0,
+        }
     }
 
     pub fn health(&self) -> f32 {
@@ -39,36 +89,34 @@ impl Ship {
         (full_repair_price * (1. - self.health())).ceil() as u32
     }
 
+    /// what a shipyard pays for the ship or credits when trading it in
+    pub fn sale_price(&self) -> u32 {
+        (self.price() as f32 * SALE_SHARE * self.health()).floor() as u32
+    }
+
     pub fn max_health(&self) -> u32 {
-        match self.size {
-            ShipSize::Small => 20,
-            ShipSize::Medium => 50,
-            ShipSize::Large => 100,
-        }
+        self.class.hull
+    }
+
+    /// tiles the ship sails per season, damaged ships are slower
+    pub fn speed(&self) -> f32 {
+        self.class.speed * (DAMAGED_SPEED + (1. - DAMAGED_SPEED) * self.health())
     }
 
     pub fn resource_space(&self) -> u32 {
-        match self.size {
-            ShipSize::Small => 20,
-            ShipSize::Medium => 50,
-            ShipSize::Large => 100,
-        }
+        self.class.capacity
+    }
+
+    pub fn cargo_used(&self) -> u32 {
+        self.cargo.values().sum()
     }
 
     pub fn price(&self) -> u32 {
-        match self.size {
-            ShipSize::Small => 2000,
-            ShipSize::Medium => 4000,
-            ShipSize::Large => 7500,
-        }
+        self.class.price
     }
 
     pub fn construction_time(&self) -> u32 {
-        match self.size {
-            ShipSize::Small => 3,
-            ShipSize::Medium => 4,
-            ShipSize::Large => 5,
-        }
+        self.class.build_time
     }
 
     pub fn repair_time(&self) -> u32 {